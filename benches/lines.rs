@@ -12,6 +12,13 @@ pub fn line_benchmark(c: &mut Criterion) {
             p.line_to(black_box(Hex::splat(*dist))).collect::<Vec<_>>()
         })
     });
+    group.bench_with_input(BenchmarkId::new("Integer Line", dist), &dist, |b, dist| {
+        b.iter(|| {
+            let p = black_box(Hex::ZERO);
+            p.integer_line_to(black_box(Hex::splat(*dist)))
+                .collect::<Vec<_>>()
+        })
+    });
     group.finish();
 }
 
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy::render::mesh::Indices;
 use bevy::render::render_resource::PrimitiveTopology;
 use bevy::time::common_conditions::on_timer;
+use bevy::window::PrimaryWindow;
 use hexx::shapes;
 use hexx::*;
 use std::collections::HashMap;
@@ -13,6 +14,8 @@ const HEX_SIZE: Vec2 = Vec2::splat(1.0);
 const COLUMN_HEIGHT: f32 = 10.0;
 /// Map radius
 const MAP_RADIUS: u32 = 20;
+/// World space scale of hex columns
+const COLUMN_SCALE: f32 = 0.9;
 /// Animation time step
 const TIME_STEP: Duration = Duration::from_millis(100);
 
@@ -25,14 +28,20 @@ pub fn main() {
         .add_plugins(DefaultPlugins)
         .add_startup_system(setup_camera)
         .add_startup_system(setup_grid)
+        .init_resource::<HighlightedHexes>()
+        .init_resource::<SelectedHex>()
         .add_system(animate_rings.run_if(on_timer(TIME_STEP)))
+        .add_system(handle_input)
+        .add_system(update_materials.after(animate_rings).after(handle_input))
         .run();
 }
 
 #[derive(Debug, Resource)]
 struct Map {
+    layout: HexLayout,
     entities: HashMap<Hex, Entity>,
     highlighted_material: Handle<StandardMaterial>,
+    selected_material: Handle<StandardMaterial>,
     default_material: Handle<StandardMaterial>,
 }
 
@@ -42,6 +51,10 @@ struct HighlightedHexes {
     hexes: Vec<Hex>,
 }
 
+/// Column under the cursor
+#[derive(Debug, Default, Resource)]
+struct SelectedHex(Option<Hex>);
+
 /// 3D Orthogrpahic camera setup
 fn setup_camera(mut commands: Commands) {
     let transform = Transform::from_xyz(0.0, 60.0, 60.0).looking_at(Vec3::ZERO, Vec3::Y);
@@ -68,6 +81,7 @@ fn setup_grid(
     // materials
     let default_material = materials.add(Color::WHITE.into());
     let highlighted_material = materials.add(Color::YELLOW.into());
+    let selected_material = materials.add(Color::RED.into());
     // mesh
     let mesh = hexagonal_column(&layout);
     let mesh_handle = meshes.add(mesh);
@@ -77,8 +91,8 @@ fn setup_grid(
             let pos = layout.hex_to_world_pos(hex);
             let id = commands
                 .spawn(PbrBundle {
-                    transform: Transform::from_xyz(pos.x, hex.length() as f32 / 2.0, pos.y)
-                        .with_scale(Vec3::splat(0.9)),
+                    transform: Transform::from_xyz(pos.x, column_base(hex), pos.y)
+                        .with_scale(Vec3::splat(COLUMN_SCALE)),
                     mesh: mesh_handle.clone(),
                     material: default_material.clone(),
                     ..default()
@@ -88,38 +102,83 @@ fn setup_grid(
         })
         .collect();
     commands.insert_resource(Map {
+        layout,
         entities,
         highlighted_material,
+        selected_material,
         default_material,
     });
 }
 
-fn animate_rings(
-    mut commands: Commands,
+/// World space height of the bottom of the `hex` column
+fn column_base(hex: Hex) -> f32 {
+    hex.length() as f32 / 2.0
+}
+
+/// Input interaction: picks the column under the cursor
+fn handle_input(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
     map: Res<Map>,
-    mut highlighted_hexes: Local<HighlightedHexes>,
+    mut selected: ResMut<SelectedHex>,
 ) {
-    // Clear highlighted hexes materials
-    for entity in highlighted_hexes
-        .hexes
-        .iter()
-        .filter_map(|h| map.entities.get(h))
-    {
-        commands
-            .entity(*entity)
-            .insert(map.default_material.clone());
+    let window = windows.single();
+    let (camera, camera_transform) = cameras.single();
+    let Some(ray) = window
+        .cursor_position()
+        .and_then(|pos| camera.viewport_to_world(camera_transform, pos))
+    else {
+        return;
+    };
+    let hit = map
+        .layout
+        .column_raycast(ray.origin, ray.direction, 1000.0, |hex| {
+            map.entities.contains_key(&hex).then(|| {
+                let base = column_base(hex);
+                [base, base + COLUMN_HEIGHT * COLUMN_SCALE]
+            })
+        });
+    let hex = hit.map(|hit| hit.hex);
+    if selected.0 != hex {
+        selected.0 = hex;
     }
+}
+
+fn animate_rings(mut highlighted_hexes: ResMut<HighlightedHexes>) {
     highlighted_hexes.ring += 1;
     if highlighted_hexes.ring > MAP_RADIUS {
         highlighted_hexes.ring = 0;
     }
     highlighted_hexes.hexes = Hex::ZERO.ring(highlighted_hexes.ring).collect();
+}
+
+/// Applies the column materials, the selected column being drawn over the highlighted ring
+fn update_materials(
+    mut commands: Commands,
+    map: Res<Map>,
+    highlighted_hexes: Res<HighlightedHexes>,
+    selected: Res<SelectedHex>,
+    mut colored_hexes: Local<Vec<Hex>>,
+) {
+    if !highlighted_hexes.is_changed() && !selected.is_changed() {
+        return;
+    }
+    // Clear previous materials
+    for entity in colored_hexes.drain(..).filter_map(|h| map.entities.get(&h)) {
+        commands
+            .entity(*entity)
+            .insert(map.default_material.clone());
+    }
     // Draw a ring
     for h in &highlighted_hexes.hexes {
         if let Some(e) = map.entities.get(h) {
             commands.entity(*e).insert(map.highlighted_material.clone());
         }
     }
+    if let Some(e) = selected.0.and_then(|h| map.entities.get(&h)) {
+        commands.entity(*e).insert(map.selected_material.clone());
+    }
+    colored_hexes.extend(highlighted_hexes.hexes.iter().copied().chain(selected.0));
 }
 
 /// Compute a bevy mesh from the layout
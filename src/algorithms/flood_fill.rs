@@ -0,0 +1,161 @@
+use crate::{Hex, HexMap};
+use std::collections::HashSet;
+
+fn fill(
+    start: Hex,
+    neighbors: impl Fn(Hex) -> [Hex; 6],
+    filter: impl Fn(Hex) -> bool,
+) -> HashSet<Hex> {
+    let mut res = HashSet::new();
+    if !filter(start) {
+        return res;
+    }
+    res.insert(start);
+    let mut open = vec![start];
+    while let Some(coord) = open.pop() {
+        for neighbor in neighbors(coord) {
+            if !res.contains(&neighbor) && filter(neighbor) {
+                res.insert(neighbor);
+                open.push(neighbor);
+            }
+        }
+    }
+    res
+}
+
+fn components(
+    coords: impl IntoIterator<Item = Hex>,
+    neighbors: impl Fn(Hex) -> [Hex; 6],
+) -> Vec<HashSet<Hex>> {
+    let coords: Vec<_> = coords.into_iter().collect();
+    let mut remaining: HashSet<_> = coords.iter().copied().collect();
+    let mut res = Vec::new();
+    for coord in coords {
+        // Every coordinate is removed from `remaining` once visited
+        if !remaining.remove(&coord) {
+            continue;
+        }
+        let mut component = HashSet::from([coord]);
+        let mut open = vec![coord];
+        while let Some(current) = open.pop() {
+            for neighbor in neighbors(current) {
+                if remaining.remove(&neighbor) {
+                    component.insert(neighbor);
+                    open.push(neighbor);
+                }
+            }
+        }
+        res.push(component);
+    }
+    res
+}
+
+/// Computes all coordinates connected to `start` for which `filter` returns `true`.
+/// If `start` itself doesn't match `filter` the returned set is empty.
+///
+/// Connections are computed using [`Hex::all_neighbors`].
+///
+/// > The `filter` must describe a finite area, otherwise this function will never return.
+/// > You may use [`HexBounds`] to limit the area.
+///
+/// # Examples
+///
+/// - Find a lake, bounded to a map radius
+///
+/// ```rust
+/// # use hexx::*;
+/// # use std::collections::HashSet;
+/// use hexx::algorithms::flood_fill;
+///
+/// let bounds = HexBounds::new(Hex::ZERO, 20);
+/// let water: HashSet<Hex> = HashSet::new();
+/// // Add water coordinates
+/// // water.insert(hex(2, 0));
+/// // ..
+/// let lake = flood_fill(hex(1, 2), |h| bounds.is_in_bounds(h) && water.contains(&h));
+/// ```
+///
+/// [`HexBounds`]: crate::HexBounds
+pub fn flood_fill(start: Hex, filter: impl Fn(Hex) -> bool) -> HashSet<Hex> {
+    fill(start, Hex::all_neighbors, filter)
+}
+
+/// Computes all coordinates connected to `start` for which `filter` returns `true`, in a
+/// *wraparound* `map`.
+/// If `start` itself doesn't match `filter` the returned set is empty.
+///
+/// Connections are computed using [`HexMap::wrapped_neighbors`], so every returned coordinate
+/// is in the map bounds.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::wrapped_flood_fill;
+///
+/// let map = HexMap::new(10);
+/// let area = wrapped_flood_fill(hex(1, 2), &map, |h| h.x != 0);
+/// assert_eq!(area.len(), map.hex_count() - 21);
+/// ```
+pub fn wrapped_flood_fill(start: Hex, map: &HexMap, filter: impl Fn(Hex) -> bool) -> HashSet<Hex> {
+    fill(map.wrapped_hex(start), |h| map.wrapped_neighbors(h), filter)
+}
+
+/// Labels every coordinate of `coords` into connected regions, using [`Hex::all_neighbors`].
+///
+/// Each returned set is a region (a *connected component*). Regions are ordered by their first
+/// coordinate appearance in `coords`, the index of a region can therefore be used as a label.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::connected_components;
+///
+/// let islands = hex(-10, 0).range(2).chain(hex(10, 0).range(3));
+/// let regions = connected_components(islands);
+/// assert_eq!(regions.len(), 2);
+/// assert_eq!(regions[0].len(), Hex::range_count(2));
+/// assert_eq!(regions[1].len(), Hex::range_count(3));
+/// ```
+pub fn connected_components(coords: impl IntoIterator<Item = Hex>) -> Vec<HashSet<Hex>> {
+    components(coords, Hex::all_neighbors)
+}
+
+/// Labels every coordinate of `coords` into connected regions of a *wraparound* `map`, using
+/// [`HexMap::wrapped_neighbors`].
+///
+/// `coords` are expected to be in the map bounds, see [`HexMap::wrapped_hex`].
+///
+/// See [`connected_components`] for more information.
+pub fn wrapped_connected_components(
+    coords: impl IntoIterator<Item = Hex>,
+    map: &HexMap,
+) -> Vec<HashSet<Hex>> {
+    components(coords, |h| map.wrapped_neighbors(h))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flood_fill_ring() {
+        let wall: HashSet<_> = Hex::ZERO.ring(5).collect();
+        let inside = flood_fill(Hex::ZERO, |h| !wall.contains(&h));
+        assert_eq!(inside, Hex::ZERO.range(4).collect());
+        assert!(flood_fill(Hex::new(5, 0), |h| !wall.contains(&h)).is_empty());
+    }
+
+    #[test]
+    fn wrapped_components() {
+        let map = HexMap::new(3);
+        // Two opposite map borders, connected through wrapping
+        let coords: Vec<_> = Hex::ZERO
+            .ring_edge(3, crate::DiagonalDirection::Right)
+            .chain(Hex::ZERO.ring_edge(3, crate::DiagonalDirection::Left))
+            .collect();
+        assert_eq!(connected_components(coords.clone()).len(), 2);
+        assert_eq!(wrapped_connected_components(coords, &map).len(), 1);
+    }
+}
@@ -5,6 +5,8 @@ use std::collections::HashSet;
 /// This algorithm takes in account coordinates *visibility* through the `blocking` argument.
 /// (*Blocking* coordinates should return `true`)
 ///
+/// See [`integer_range_fov`] for a deterministic, float-free version.
+///
 /// # Examples
 ///
 /// - Compute field of view with no boundaries and some blocking tiles
@@ -23,17 +25,36 @@ use std::collections::HashSet;
 /// let fov = range_fov(pos, range, |h| blocking_coords.contains(&h));
 /// ```
 pub fn range_fov(coord: Hex, range: u32, blocking: impl Fn(Hex) -> bool) -> HashSet<Hex> {
-    coord
-        .ring(range)
-        .flat_map(|target| coord.line_to(target).take_while(|h| !blocking(*h)))
-        .collect()
+    lines_of_sight(coord, coord.ring(range), Hex::line_to, blocking)
+}
+
+/// Computes a field of view around `coord` in a given `range`, like [`range_fov`].
+///
+/// Lines of sight are computed with [`Hex::integer_line_to`], making the result deterministic
+/// across platforms.
+///
+/// # Examples
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::integer_range_fov;
+///
+/// let fov = integer_range_fov(hex(0, 0), 10, |h| h == hex(2, 0));
+/// assert!(fov.contains(&hex(1, 0)));
+/// assert!(!fov.contains(&hex(3, 0)));
+/// ```
+pub fn integer_range_fov(coord: Hex, range: u32, blocking: impl Fn(Hex) -> bool) -> HashSet<Hex> {
+    lines_of_sight(coord, coord.ring(range), Hex::integer_line_to, blocking)
 }
 
 /// Computes a field of view around `coord` in a given `range` towards `direction` with 120 degrees
 /// vision.
+///
 /// This algorithm takes in account coordinates *visibility* through the `blocking` argument.
 /// (*Blocking* coordinates should return `true`)
 ///
+/// See [`integer_directional_fov`] for a deterministic, float-free version.
+///
 /// # Examples
 ///
 /// - Compute drectional field of view with no boundaries and some blocking tiles
@@ -58,13 +79,55 @@ pub fn directional_fov(
     direction: Direction,
     blocking: impl Fn(Hex) -> bool,
 ) -> HashSet<Hex> {
+    let targets = directional_targets(coord, range, direction);
+    lines_of_sight(coord, targets, Hex::line_to, blocking)
+}
+
+/// Computes a field of view around `coord` in a given `range` towards `direction` with 120 degrees
+/// vision, like [`directional_fov`].
+///
+/// Lines of sight are computed with [`Hex::integer_line_to`], making the result deterministic
+/// across platforms.
+///
+/// # Examples
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::integer_directional_fov;
+///
+/// let fov = integer_directional_fov(hex(0, 0), 10, Direction::Top, |_| false);
+/// assert!(fov.contains(&hex(0, -5)));
+/// assert!(!fov.contains(&hex(0, 5)));
+/// ```
+pub fn integer_directional_fov(
+    coord: Hex,
+    range: u32,
+    direction: Direction,
+    blocking: impl Fn(Hex) -> bool,
+) -> HashSet<Hex> {
+    let targets = directional_targets(coord, range, direction);
+    lines_of_sight(coord, targets, Hex::integer_line_to, blocking)
+}
+
+/// Retrieves the `range` ring coordinates around `coord` in the 120 degrees wedge towards
+/// `direction`
+fn directional_targets(coord: Hex, range: u32, direction: Direction) -> impl Iterator<Item = Hex> {
     let [a, b] = [direction.diagonal_left(), direction.diagonal_right()];
-    coord
-        .ring(range)
-        .filter(|h| {
-            let way = coord.diagonal_way_to(*h);
-            way == a || way == b
-        })
-        .flat_map(|target| coord.line_to(target).take_while(|h| !blocking(*h)))
+    coord.ring(range).filter(move |h| {
+        let way = coord.diagonal_way_to(*h);
+        way == a || way == b
+    })
+}
+
+/// Collects the coordinates of every `line` from `coord` to `targets`, until a `blocking`
+/// coordinate
+fn lines_of_sight<L: Iterator<Item = Hex>>(
+    coord: Hex,
+    targets: impl Iterator<Item = Hex>,
+    line: impl Fn(Hex, Hex) -> L,
+    blocking: impl Fn(Hex) -> bool,
+) -> HashSet<Hex> {
+    targets
+        .flat_map(|target| line(coord, target).take_while(|h| !blocking(*h)))
         .collect()
 }
@@ -1,5 +1,9 @@
+mod flood_fill;
 mod fov;
 mod pathfinding;
 
-pub use fov::{directional_fov, range_fov};
-pub use pathfinding::a_star;
+pub use flood_fill::{
+    connected_components, flood_fill, wrapped_connected_components, wrapped_flood_fill,
+};
+pub use fov::{directional_fov, integer_directional_fov, integer_range_fov, range_fov};
+pub use pathfinding::{a_star, edge_a_star};
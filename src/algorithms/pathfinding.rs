@@ -1,33 +1,36 @@
-use crate::Hex;
-use std::collections::{BinaryHeap, HashMap};
+use crate::{Hex, HexEdge, HexVertex};
+use std::{
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
 
-struct Node {
-    coord: Hex,
+struct Node<T> {
+    coord: T,
     /// distance
     heuristic: u32,
 }
 
-impl PartialEq for Node {
+impl<T> PartialEq for Node<T> {
     fn eq(&self, other: &Self) -> bool {
         self.heuristic == other.heuristic
     }
 }
 
-impl Eq for Node {}
+impl<T> Eq for Node<T> {}
 
-impl PartialOrd for Node {
+impl<T> PartialOrd for Node<T> {
     fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
-        rhs.heuristic.partial_cmp(&self.heuristic)
+        Some(self.cmp(rhs))
     }
 }
 
-impl Ord for Node {
+impl<T> Ord for Node<T> {
     fn cmp(&self, rhs: &Self) -> std::cmp::Ordering {
         rhs.heuristic.cmp(&self.heuristic)
     }
 }
 
-fn reconstruct_path(came_from: &HashMap<Hex, Hex>, end: Hex) -> Vec<Hex> {
+fn reconstruct_path<T: Copy + Eq + Hash>(came_from: &HashMap<T, T>, end: T) -> Vec<T> {
     let mut path: Vec<_> =
         std::iter::successors(Some(end), move |&current| came_from.get(&current).copied())
             .collect();
@@ -36,9 +39,11 @@ fn reconstruct_path(came_from: &HashMap<Hex, Hex>, end: Hex) -> Vec<Hex> {
 }
 
 /// Performs A star pathfinding between `start` and `end`.
+///
 /// The `cost` parameter should give the cost of each coordinate (`Some`) or indicate the
 /// coordinate is not included in the pathfinding (`None`).
 /// This function already takes care of heuristics based on the distance between `start` and `end`.
+/// It only relies on integer arithmetic and is therefore deterministic across platforms.
 ///
 /// # Examples
 ///
@@ -125,3 +130,122 @@ pub fn a_star(start: Hex, end: Hex, cost: impl Fn(Hex) -> Option<u32>) -> Option
     }
     None
 }
+
+/// Performs A star pathfinding along the edges of the hexagonal grid, from the `start` vertex to
+/// the `end` vertex.
+///
+/// The `cost` parameter should give the cost of each edge (`Some`) or indicate the edge is
+/// not included in the pathfinding (`None`).
+/// Like [`a_star`], this function already takes care of heuristics based on the distance
+/// between `start` and `end`, and only relies on integer arithmetic.
+///
+/// The path is returned as the list of successive edges, from `start` to `end`. If
+/// `start == end` the path is empty.
+///
+/// # Example
+///
+/// - Compute a river path which can't flow through the borders of a lake
+///
+/// ```rust
+/// # use hexx::*;
+/// # use std::collections::HashSet;
+/// use hexx::algorithms::edge_a_star;
+///
+/// let start = HexVertex::new(hex(-5, 0), Direction::Top);
+/// let end = HexVertex::new(hex(5, 0), Direction::Top);
+/// let lake: HashSet<Hex> = Hex::ZERO.range(1).collect();
+/// let path = edge_a_star(start, end, |edge| {
+///     (!edge.hexes().iter().any(|h| lake.contains(h))).then_some(1)
+/// })
+/// .unwrap();
+/// assert!(path[0].vertices().contains(&start));
+/// assert!(path.iter().all(|edge| !edge.hexes().iter().any(|h| lake.contains(h))));
+/// ```
+pub fn edge_a_star(
+    start: HexVertex,
+    end: HexVertex,
+    cost: impl Fn(HexEdge) -> Option<u32>,
+) -> Option<Vec<HexEdge>> {
+    let end_hexes = end.hexes();
+    let heuristic = |v: HexVertex| {
+        v.hexes()
+            .into_iter()
+            .flat_map(|a| end_hexes.map(|b| a.unsigned_distance_to(b)))
+            .min()
+            .unwrap_or_default()
+    };
+
+    let start_node = Node {
+        coord: start,
+        heuristic: heuristic(start),
+    };
+    let mut open = BinaryHeap::new();
+    open.push(start_node);
+    let mut costs = HashMap::new();
+    costs.insert(start, 0);
+    let mut came_from = HashMap::new();
+
+    while let Some(node) = open.pop() {
+        if node.coord == end {
+            let path = reconstruct_path(&came_from, end);
+            return Some(
+                path.windows(2)
+                    .filter_map(|w| w[0].edges().into_iter().find(|e| w[1].edges().contains(e)))
+                    .collect(),
+            );
+        }
+        for (neighbor, edge) in node.coord.neighbors().into_iter().zip(node.coord.edges()) {
+            let Some(cost) = cost(edge) else { continue };
+            let neighbor_cost = costs[&node.coord] + cost;
+            if !costs.contains_key(&neighbor) || costs[&neighbor] > neighbor_cost {
+                came_from.insert(neighbor, node.coord);
+                costs.insert(neighbor, neighbor_cost);
+                open.push(Node {
+                    coord: neighbor,
+                    heuristic: neighbor_cost + heuristic(neighbor),
+                });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction;
+
+    #[test]
+    fn edge_path() {
+        let start = HexVertex::new(Hex::new(-3, 1), Direction::TopRight);
+        let end = HexVertex::new(Hex::new(4, -2), Direction::BottomLeft);
+        let path = edge_a_star(start, end, |_| Some(1)).unwrap();
+        assert!(!path.is_empty());
+        // The path is continuous from `start` to `end`
+        let mut current = start;
+        for edge in &path {
+            let [a, b] = edge.vertices();
+            current = if a == current {
+                b
+            } else {
+                assert_eq!(b, current);
+                a
+            };
+        }
+        assert_eq!(current, end);
+        assert_eq!(edge_a_star(start, start, |_| Some(1)), Some(vec![]));
+    }
+
+    #[test]
+    fn edge_path_blocked() {
+        let start = HexVertex::new(Hex::ZERO, Direction::Top);
+        let end = HexVertex::new(Hex::new(5, 0), Direction::Top);
+        // Every edge of `start` is forbidden
+        let forbidden = start.edges();
+        let path = edge_a_star(start, end, |e| (!forbidden.contains(&e)).then_some(1));
+        assert_eq!(path, None);
+        // Forbid the direct edges, forcing a detour
+        let path = edge_a_star(start, end, |e| (e != forbidden[0]).then_some(1)).unwrap();
+        assert!(!path.contains(&forbidden[0]));
+    }
+}
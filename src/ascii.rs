@@ -0,0 +1,291 @@
+use crate::{Hex, HexBounds, OffsetHexMode};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Error returned when parsing ASCII art fails, because a character is not aligned on the
+/// grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseAsciiError {
+    /// The zero based line of the misaligned character
+    pub line: usize,
+    /// The zero based character column of the misaligned character
+    pub column: usize,
+}
+
+impl Display for ParseAsciiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "misaligned hexagon at line {}, column {}",
+            self.line, self.column
+        )
+    }
+}
+
+impl Error for ParseAsciiError {}
+
+/// Returns `true` if the offset row or column `index` is shoved according to `mode`
+const fn is_shoved(index: i32, mode: OffsetHexMode) -> bool {
+    let odd = index & 1 == 1;
+    match mode {
+        OffsetHexMode::EvenColumns | OffsetHexMode::EvenRows => !odd,
+        OffsetHexMode::OddColumns | OffsetHexMode::OddRows => odd,
+    }
+}
+
+/// Computes the text `[line, column]` position of the `[col, row]` offset coordinates
+#[allow(clippy::cast_sign_loss)]
+const fn text_pos(
+    [col, row]: [i32; 2],
+    [min_col, min_row]: [i32; 2],
+    mode: OffsetHexMode,
+) -> [usize; 2] {
+    let [x, y] = [(col - min_col) as usize, (row - min_row) as usize];
+    match mode {
+        OffsetHexMode::EvenRows | OffsetHexMode::OddRows => {
+            [y, 2 * x + is_shoved(row, mode) as usize]
+        }
+        OffsetHexMode::EvenColumns | OffsetHexMode::OddColumns => {
+            [2 * y + is_shoved(col, mode) as usize, 2 * x]
+        }
+    }
+}
+
+/// Renders the rectangle of offset coordinates between `min` and `max` (included) as ASCII
+/// art. Offset coordinates are `[COLUMN, ROW]`, see [`Hex::to_offset_coordinates`].
+///
+/// The `draw` callback gives the character of every coordinate, use a whitespace to leave a
+/// coordinate empty. Trailing whitespace is trimmed from every line.
+///
+/// The grid is drawn following `mode`:
+///
+/// * [`OffsetHexMode::EvenRows`] and [`OffsetHexMode::OddRows`] draw *pointy* grids, where each
+///   text line is an offset row and shoved rows are indented by one character:
+///
+/// ```text
+/// a b c
+///  d e f
+/// g h i
+/// ```
+///
+/// * [`OffsetHexMode::EvenColumns`] and [`OffsetHexMode::OddColumns`] draw *flat* grids, where
+///   every offset column uses one character every two lines, and shoved columns start one
+///   line lower:
+///
+/// ```text
+/// a   c
+///   b   d
+/// e   g
+///   f   h
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let mode = OffsetHexMode::OddRows;
+/// let art = ascii::render([0, 0], [2, 2], mode, |h| if h == Hex::ZERO { '#' } else { '.' });
+/// assert_eq!(art, "# . .\n . . .\n. . .");
+/// ```
+#[must_use]
+pub fn render(
+    min: [i32; 2],
+    max: [i32; 2],
+    mode: OffsetHexMode,
+    draw: impl Fn(Hex) -> char,
+) -> String {
+    let mut lines: Vec<Vec<char>> = Vec::new();
+    for col in min[0]..=max[0] {
+        for row in min[1]..=max[1] {
+            let [line, column] = text_pos([col, row], min, mode);
+            if lines.len() <= line {
+                lines.resize(line + 1, Vec::new());
+            }
+            let line = &mut lines[line];
+            if line.len() <= column {
+                line.resize(column + 1, ' ');
+            }
+            line[column] = draw(Hex::from_offset_coordinates([col, row], mode));
+        }
+    }
+    lines
+        .into_iter()
+        .map(|line| line.into_iter().collect::<String>().trim_end().to_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Computes the minimum and maximum offset coordinates of `bounds`
+#[must_use]
+pub fn offset_rect(bounds: HexBounds, mode: OffsetHexMode) -> [[i32; 2]; 2] {
+    bounds
+        .all_coords()
+        .map(|h| h.to_offset_coordinates(mode))
+        .fold([[i32::MAX; 2], [i32::MIN; 2]], |[min, max], [col, row]| {
+            [
+                [min[0].min(col), min[1].min(row)],
+                [max[0].max(col), max[1].max(row)],
+            ]
+        })
+}
+
+/// Renders all coordinates in `bounds` as ASCII art, see [`render`].
+///
+/// The top left of the text matches the minimum offset coordinates given by [`offset_rect`],
+/// which should be used as the `origin` to [`parse`] the text back.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let bounds = HexBounds::new(Hex::ZERO, 1);
+/// let mode = OffsetHexMode::OddRows;
+/// let art = ascii::render_bounds(bounds, mode, |_| 'o');
+/// assert_eq!(art, " o o\no o o\n o o");
+/// ```
+#[must_use]
+pub fn render_bounds(bounds: HexBounds, mode: OffsetHexMode, draw: impl Fn(Hex) -> char) -> String {
+    let [min, max] = offset_rect(bounds, mode);
+    render(min, max, mode, |h| {
+        if bounds.is_in_bounds(h) {
+            draw(h)
+        } else {
+            ' '
+        }
+    })
+}
+
+/// Parses ASCII art into coordinates and their character, see [`render`] for the format.
+///
+/// The first character of the first line matches the `origin` offset coordinates
+/// (`[COLUMN, ROW]`), which is usually `[0, 0]`. Whitespace is ignored, any other character
+/// is considered a hexagon.
+///
+/// # Errors
+///
+/// Will return an error if a character is not aligned on the grid
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let mode = OffsetHexMode::OddRows;
+/// let hexes = ascii::parse("# .\n . #", [0, 0], mode).unwrap();
+/// assert_eq!(
+///     hexes,
+///     vec![
+///         (hex(0, 0), '#'),
+///         (hex(1, 0), '.'),
+///         (hex(0, 1), '.'),
+///         (hex(1, 1), '#'),
+///     ]
+/// );
+/// ```
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub fn parse(
+    text: &str,
+    origin: [i32; 2],
+    mode: OffsetHexMode,
+) -> Result<Vec<(Hex, char)>, ParseAsciiError> {
+    let [min_col, min_row] = origin;
+    let mut res = Vec::new();
+    for (line, content) in text.lines().enumerate() {
+        for (column, c) in content
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| !c.is_whitespace())
+        {
+            let error = ParseAsciiError { line, column };
+            let [line, column] = [line as i32, column as i32];
+            let [col, row] = match mode {
+                OffsetHexMode::EvenRows | OffsetHexMode::OddRows => {
+                    let row = min_row + line;
+                    let column = column - i32::from(is_shoved(row, mode));
+                    if column < 0 || column % 2 != 0 {
+                        return Err(error);
+                    }
+                    [min_col + column / 2, row]
+                }
+                OffsetHexMode::EvenColumns | OffsetHexMode::OddColumns => {
+                    if column % 2 != 0 {
+                        return Err(error);
+                    }
+                    let col = min_col + column / 2;
+                    let line = line - i32::from(is_shoved(col, mode));
+                    if line < 0 || line % 2 != 0 {
+                        return Err(error);
+                    }
+                    [col, min_row + line / 2]
+                }
+            };
+            res.push((Hex::from_offset_coordinates([col, row], mode), c));
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const MODES: [OffsetHexMode; 4] = [
+        OffsetHexMode::EvenColumns,
+        OffsetHexMode::OddColumns,
+        OffsetHexMode::EvenRows,
+        OffsetHexMode::OddRows,
+    ];
+
+    #[test]
+    fn round_trip() {
+        let bounds = HexBounds::new(Hex::new(3, -1), 3);
+        let chars: HashMap<Hex, char> = bounds
+            .all_coords()
+            .zip(('a'..='z').chain('A'..='Z').cycle())
+            .collect();
+        for mode in MODES {
+            let art = render_bounds(bounds, mode, |h| chars[&h]);
+            let [min, _] = offset_rect(bounds, mode);
+            let parsed = parse(&art, min, mode).unwrap();
+            assert_eq!(parsed.len(), bounds.hex_count());
+            for (hex, c) in parsed {
+                assert_eq!(chars[&hex], c);
+            }
+        }
+    }
+
+    #[test]
+    fn flat_layout() {
+        let mode = OffsetHexMode::OddColumns;
+        let letters = ['a', 'b', 'c', 'd'];
+        let art = render([0, 0], [3, 1], mode, |h| {
+            letters[usize::try_from(h.to_offset_coordinates(mode)[0]).unwrap()]
+        });
+        assert_eq!(art, "a   c\n  b   d\na   c\n  b   d");
+        let hexes = parse(&art, [0, 0], mode).unwrap();
+        assert_eq!(hexes.len(), 8);
+        // Lines are parsed in order: `a` and `c` first, then `b` and `d`
+        assert_eq!(hexes[2].1, 'b');
+        assert_eq!(
+            hexes[0].0.neighbor(crate::Direction::BottomRight),
+            hexes[2].0
+        );
+    }
+
+    #[test]
+    fn misaligned() {
+        let mode = OffsetHexMode::EvenRows;
+        assert_eq!(
+            parse("a b\nc d", [0, 0], mode),
+            Err(ParseAsciiError { line: 0, column: 0 })
+        );
+        assert!(parse(" a b\nc d", [0, 0], mode).is_ok());
+        let mode = OffsetHexMode::OddColumns;
+        assert_eq!(
+            parse(" a", [0, 0], mode),
+            Err(ParseAsciiError { line: 0, column: 1 })
+        );
+    }
+}
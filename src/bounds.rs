@@ -1,4 +1,4 @@
-use crate::Hex;
+use crate::{Direction, Hex, Hex64, HexVertex};
 
 /// Hexagonal bounds utils, representer as a center and radius.
 /// This type can be defined manually or from a [`Hex`] iterator.
@@ -32,10 +32,17 @@ impl HexBounds {
     /// Computes the bounds `min` and `max`
     #[inline]
     #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::manual_midpoint)]
     pub fn from_min_max(min: Hex, max: Hex) -> Self {
-        let center = (min + max) / 2;
+        // Wider integers are used to avoid overflows on extreme coordinates
+        let [min, max] = [min, max].map(Hex64::from);
+        let center = Hex64::new((min.x + max.x) / 2, (min.y + max.y) / 2);
         let radius = center.unsigned_distance_to(max) / 2;
-        Self { center, radius }
+        Self {
+            // The average of two `i32` values always fits in a `i32`
+            center: Hex::new(center.x as i32, center.y as i32),
+            radius: u32::try_from(radius).unwrap_or(u32::MAX),
+        }
     }
 
     #[inline]
@@ -61,6 +68,34 @@ impl HexBounds {
         self.center.range(self.radius)
     }
 
+    #[must_use]
+    #[inline]
+    /// Returns the number of vertices of the coordinates in bounds
+    pub const fn vertex_count(&self) -> usize {
+        let n = self.radius as usize + 1;
+        6 * n * n
+    }
+
+    /// Returns an iterator with all the vertices of the coordinates in bounds.
+    ///
+    /// Every vertex is returned once, for a total of [`Self::vertex_count`] vertices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let bounds = HexBounds::new(Hex::ZERO, 1);
+    /// assert_eq!(bounds.all_vertices().count(), 24);
+    /// ```
+    pub fn all_vertices(self) -> impl Iterator<Item = HexVertex> {
+        self.center
+            .range(self.radius + 1)
+            .flat_map(|hex| {
+                [Direction::TopRight, Direction::Top].map(|dir| HexVertex::new(hex, dir))
+            })
+            .filter(move |vertex| vertex.hexes().iter().any(|h| self.is_in_bounds(*h)))
+    }
+
     /// Computes all coordinates in the intersection between `self` and `rhs`
     pub fn intersecting_with(self, rhs: Self) -> impl Iterator<Item = Hex> {
         let [start, end] = if self.radius > rhs.radius {
@@ -77,12 +112,17 @@ impl FromIterator<Hex> for HexBounds {
         let mut min = Hex::new(i32::MAX, i32::MAX);
         let mut max = Hex::new(i32::MIN, i32::MIN);
 
+        let mut empty = true;
         for hex in iter {
+            empty = false;
             min.x = min.x.min(hex.x);
             max.x = max.x.max(hex.x);
             min.y = min.y.min(hex.y);
             max.y = max.y.max(hex.y);
         }
+        if empty {
+            return Self::new(Hex::ZERO, 0);
+        }
         Self::from_min_max(min, max)
     }
 }
@@ -99,6 +139,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_iter_extremes() {
+        let bounds: HexBounds = std::iter::empty().collect();
+        assert_eq!(bounds.center, Hex::ZERO);
+        assert_eq!(bounds.radius, 0);
+        let bounds: HexBounds = [Hex::new(i32::MIN, i32::MAX), Hex::new(i32::MAX, i32::MIN)]
+            .into_iter()
+            .collect();
+        assert_eq!(bounds.center, Hex::ZERO);
+        let bounds: HexBounds = std::iter::once(Hex::new(i32::MAX, i32::MAX)).collect();
+        assert_eq!(bounds.center, Hex::new(i32::MAX, i32::MAX));
+    }
+
     #[test]
     fn intersecting_with() {
         let ba = HexBounds::new(Hex::ZERO, 3);
@@ -1,10 +1,10 @@
-use crate::Hex;
+use crate::{Direction, Hex};
 
 /// Layout mode for [doubled] coordinates conversion. See [`Hex::to_doubled_coordinates`] and
 /// [`Hex::from_doubled_coordinates`].
 ///
 /// [doubled]: https://www.redblobgames.com/grids/hexagons/#coordinates-doubled
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
 pub enum DoubledHexMode {
     /// Doubles column values
@@ -18,7 +18,7 @@ pub enum DoubledHexMode {
 /// [`Hex::from_offset_coordinates`].
 ///
 /// [offset]: https://www.redblobgames.com/grids/hexagons/#coordinates-offset
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
 pub enum OffsetHexMode {
     /// Vertical layout, shoves even columns down
@@ -32,6 +32,174 @@ pub enum OffsetHexMode {
     OddRows,
 }
 
+/// Hexagonal [offset] coordinates, for interoperability with tilemaps storing offset
+/// coordinates.
+///
+/// Neighbors, distances and ranges are computed natively through [`Hex`], the axial
+/// coordinates, without manual conversions.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let a = OffsetHex::new(2, 3, OffsetHexMode::OddRows);
+/// let b = a.neighbor(Direction::BottomRight);
+/// assert_eq!(b, OffsetHex::new(3, 3, OffsetHexMode::OddRows));
+/// assert_eq!(a.unsigned_distance_to(b), 1);
+/// assert_eq!(Hex::from(b), Hex::from_offset_coordinates([3, 3], OffsetHexMode::OddRows));
+/// ```
+///
+/// [offset]: https://www.redblobgames.com/grids/hexagons/#coordinates-offset
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+pub struct OffsetHex {
+    /// The column coordinate
+    pub col: i32,
+    /// The row coordinate
+    pub row: i32,
+    /// The offset layout mode of the coordinates
+    pub mode: OffsetHexMode,
+}
+
+/// Hexagonal [doubled] coordinates, for interoperability with tilemaps storing doubled
+/// coordinates.
+///
+/// Neighbors, distances and ranges are computed natively through [`Hex`], the axial
+/// coordinates, without manual conversions.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let a = DoubledHex::new(4, 2, DoubledHexMode::DoubledWidth);
+/// let b = a.neighbor(Direction::BottomRight);
+/// assert_eq!(b, DoubledHex::new(6, 2, DoubledHexMode::DoubledWidth));
+/// assert_eq!(a.unsigned_distance_to(b), 1);
+/// ```
+///
+/// [doubled]: https://www.redblobgames.com/grids/hexagons/#coordinates-doubled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubledHex {
+    /// The column coordinate
+    pub col: i32,
+    /// The row coordinate
+    pub row: i32,
+    /// The doubled layout mode of the coordinates
+    pub mode: DoubledHexMode,
+}
+
+/// Implements the native operations of the `$name` coordinates, using its `$mode` and the
+/// `$to` and `$from` [`Hex`] conversion methods
+macro_rules! coordinates_impl {
+    ($name:ident, $mode:ident, $to:ident, $from:ident) => {
+        impl $name {
+            #[inline]
+            #[must_use]
+            /// Instantiates new coordinates from `col` and `row` in the given `mode`
+            pub const fn new(col: i32, row: i32, mode: $mode) -> Self {
+                Self { col, row, mode }
+            }
+
+            #[inline]
+            #[must_use]
+            /// Converts the axial coordinates `hex` in the given `mode`
+            pub const fn from_hex(hex: Hex, mode: $mode) -> Self {
+                let [col, row] = hex.$to(mode);
+                Self { col, row, mode }
+            }
+
+            #[inline]
+            #[must_use]
+            /// Converts `self` to axial coordinates
+            pub const fn to_hex(self) -> Hex {
+                Hex::$from([self.col, self.row], self.mode)
+            }
+
+            #[inline]
+            #[must_use]
+            /// Returns the `[COLUMN, ROW]` coordinates
+            pub const fn to_array(self) -> [i32; 2] {
+                [self.col, self.row]
+            }
+
+            #[inline]
+            #[must_use]
+            /// Converts `self` to the given `mode`
+            pub const fn with_mode(self, mode: $mode) -> Self {
+                Self::from_hex(self.to_hex(), mode)
+            }
+
+            #[inline]
+            #[must_use]
+            /// Retrieves the neighbor coordinates in the given `direction`, in the same mode
+            pub const fn neighbor(self, direction: Direction) -> Self {
+                Self::from_hex(self.to_hex().neighbor(direction), self.mode)
+            }
+
+            #[inline]
+            #[must_use]
+            /// Retrieves all 6 neighbor coordinates around `self`, in the same mode and in
+            /// the [`Direction::ALL_DIRECTIONS`] order
+            pub fn all_neighbors(self) -> [Self; 6] {
+                self.to_hex()
+                    .all_neighbors()
+                    .map(|hex| Self::from_hex(hex, self.mode))
+            }
+
+            #[inline]
+            #[must_use]
+            /// Computes the distance from `self` to `other` in hexagonal space, which may
+            /// use a different mode
+            pub const fn distance_to(self, other: Self) -> i32 {
+                self.to_hex().distance_to(other.to_hex())
+            }
+
+            #[inline]
+            #[must_use]
+            /// Computes the unsigned distance from `self` to `other` in hexagonal space,
+            /// which may use a different mode
+            pub const fn unsigned_distance_to(self, other: Self) -> u32 {
+                self.to_hex().unsigned_distance_to(other.to_hex())
+            }
+
+            /// Retrieves all coordinates around `self` in a given `range`, in the same mode.
+            /// See [`Hex::range`]
+            pub fn range(self, range: u32) -> impl ExactSizeIterator<Item = Self> {
+                let mode = self.mode;
+                self.to_hex()
+                    .range(range)
+                    .map(move |hex| Self::from_hex(hex, mode))
+            }
+        }
+
+        impl From<$name> for Hex {
+            fn from(value: $name) -> Self {
+                value.to_hex()
+            }
+        }
+
+        impl From<(Hex, $mode)> for $name {
+            fn from((hex, mode): (Hex, $mode)) -> Self {
+                Self::from_hex(hex, mode)
+            }
+        }
+    };
+}
+
+coordinates_impl!(
+    OffsetHex,
+    OffsetHexMode,
+    to_offset_coordinates,
+    from_offset_coordinates
+);
+coordinates_impl!(
+    DoubledHex,
+    DoubledHexMode,
+    to_doubled_coordinates,
+    from_doubled_coordinates
+);
+
 impl Hex {
     /// Converts `self` to [doubled] coordinates according to the given `mode`.
     ///
@@ -54,6 +222,7 @@ impl Hex {
     /// [offset]: https://www.redblobgames.com/grids/hexagons/#coordinates-offset
     #[inline]
     #[must_use]
+    #[allow(clippy::manual_midpoint)]
     pub const fn to_offset_coordinates(self, mode: OffsetHexMode) -> [i32; 2] {
         match mode {
             OffsetHexMode::EvenColumns => [self.x, self.y + (self.x + (self.x & 1)) / 2],
@@ -82,6 +251,7 @@ impl Hex {
     /// [axial]: https://www.redblobgames.com/grids/hexagons/#coordinates-axial
     #[inline]
     #[must_use]
+    #[allow(clippy::manual_midpoint)]
     pub const fn from_offset_coordinates([col, row]: [i32; 2], mode: OffsetHexMode) -> Self {
         match mode {
             OffsetHexMode::EvenColumns => Self::new(col, row - (col + (col & 1)) / 2),
@@ -122,4 +292,47 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn coordinates_types() {
+        let offset_modes = [
+            OffsetHexMode::OddRows,
+            OffsetHexMode::OddColumns,
+            OffsetHexMode::EvenColumns,
+            OffsetHexMode::EvenRows,
+        ];
+        let doubled_modes = [DoubledHexMode::DoubledWidth, DoubledHexMode::DoubledHeight];
+        for hex in Hex::new(3, -7).range(4) {
+            for mode in offset_modes {
+                let offset = OffsetHex::from_hex(hex, mode);
+                assert_eq!(offset.to_array(), hex.to_offset_coordinates(mode));
+                assert_eq!(Hex::from(offset), hex);
+                assert_eq!(OffsetHex::from((hex, mode)), offset);
+                for (neighbor, dir) in offset.all_neighbors().into_iter().zip(Direction::iter()) {
+                    assert_eq!(neighbor, offset.neighbor(dir));
+                    assert_eq!(neighbor.to_hex(), hex.neighbor(dir));
+                    assert_eq!(neighbor.distance_to(offset), 1);
+                }
+                let range: Vec<_> = offset.range(2).collect();
+                assert_eq!(range.len(), 19);
+                assert!(range.iter().all(|o| o.unsigned_distance_to(offset) <= 2));
+                for other in offset_modes {
+                    let converted = offset.with_mode(other);
+                    assert_eq!(converted.to_hex(), hex);
+                    assert_eq!(converted.distance_to(offset), 0);
+                }
+            }
+            for mode in doubled_modes {
+                let doubled = DoubledHex::from_hex(hex, mode);
+                assert_eq!(doubled.to_array(), hex.to_doubled_coordinates(mode));
+                assert_eq!(Hex::from(doubled), hex);
+                for dir in Direction::iter() {
+                    let neighbor = doubled.neighbor(dir);
+                    assert_eq!(neighbor.to_hex(), hex.neighbor(dir));
+                    assert_eq!(neighbor.unsigned_distance_to(doubled), 1);
+                }
+                assert_eq!(doubled.range(3).len(), 37);
+            }
+        }
+    }
 }
@@ -360,9 +360,16 @@ impl DiagonalDirection {
 
     #[inline]
     #[must_use]
-    /// Returns the angle in radians of the given direction in the given `orientation`
+    /// Returns the angle in radians of the given direction in the given `orientation`, which
+    /// may be rotated (see [`HexOrientation::from_angle`]).
+    ///
+    /// For [`HexOrientation::flat`] and [`HexOrientation::pointy`] this matches
+    /// [`Self::angle_flat`] and [`Self::angle_pointy`].
+    ///
+    /// Note that *pointy* angles do not follow the axes of [`HexOrientation::pointy`]
+    /// layouts: their world/pixel space angles are 60 degrees more. See [`Direction::angle`].
     pub fn angle(self, orientation: &HexOrientation) -> f32 {
-        self.angle_pointy() - orientation.angle_offset
+        self.angle_flat() - orientation.angle_offset
     }
 
     #[inline]
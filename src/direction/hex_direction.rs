@@ -363,9 +363,18 @@ impl Direction {
 
     #[inline]
     #[must_use]
-    /// Returns the angle in radians of the given direction in the given `orientation`
+    /// Returns the angle in radians of the given direction in the given `orientation`, which
+    /// may be rotated (see [`HexOrientation::from_angle`]).
+    ///
+    /// For [`HexOrientation::flat`] and [`HexOrientation::pointy`] this matches
+    /// [`Self::angle_flat`] and [`Self::angle_pointy`].
+    ///
+    /// Note that *pointy* angles do not follow the axes of [`HexOrientation::pointy`]
+    /// layouts: their world/pixel space angles are 60 degrees more (`Top` is at 120 degrees,
+    /// not 60). Use [`crate::HexLayout::direction_angle`] for the actual world/pixel angle of a
+    /// direction.
     pub fn angle(self, orientation: &HexOrientation) -> f32 {
-        self.angle_pointy() - orientation.angle_offset
+        self.angle_flat() - orientation.angle_offset
     }
 
     #[inline]
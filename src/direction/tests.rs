@@ -3,7 +3,6 @@
 use super::*;
 use crate::HexOrientation;
 use std::f32::consts::PI;
-use std::f32::EPSILON;
 
 mod hex_directions {
     use super::Direction::*;
@@ -58,7 +57,7 @@ mod hex_directions {
             (BottomRight, 330.0),
         ];
         for (dir, angle) in expected {
-            assert!(dir.angle_flat_degrees() - angle <= EPSILON);
+            assert!(dir.angle_flat_degrees() - angle <= f32::EPSILON);
         }
     }
 
@@ -74,8 +73,8 @@ mod hex_directions {
         ];
         let orientation = HexOrientation::flat();
         for (dir, angle) in expected {
-            assert!(dir.angle_flat() - angle <= EPSILON);
-            assert!(dir.angle(&orientation) - angle <= EPSILON);
+            assert!(dir.angle_flat() - angle <= f32::EPSILON);
+            assert!((dir.angle(&orientation) - angle).abs() <= 1e-6);
         }
     }
 
@@ -90,7 +89,7 @@ mod hex_directions {
             (BottomRight, 300.0),
         ];
         for (dir, angle) in expected {
-            assert!(dir.angle_pointy_degrees() - angle <= EPSILON);
+            assert!(dir.angle_pointy_degrees() - angle <= f32::EPSILON);
         }
     }
 
@@ -106,8 +105,8 @@ mod hex_directions {
         ];
         let orientation = HexOrientation::pointy();
         for (dir, angle) in expected {
-            assert!(dir.angle_pointy() - angle <= EPSILON);
-            assert!(dir.angle(&orientation) - angle <= EPSILON);
+            assert!(dir.angle_pointy() - angle <= f32::EPSILON);
+            assert!((dir.angle(&orientation) - angle).abs() <= 1e-6);
         }
     }
 
@@ -135,7 +134,7 @@ mod diagonal_direction {
     fn flat_angles_rad() {
         for dir in DiagonalDirection::ALL_DIRECTIONS {
             let expected = dir.direction_left().angle_flat() - PI / 6.0;
-            assert!(dir.angle_flat() - expected <= EPSILON);
+            assert!(dir.angle_flat() - expected <= f32::EPSILON);
         }
     }
 
@@ -143,7 +142,7 @@ mod diagonal_direction {
     fn pointy_angles_rad() {
         for dir in DiagonalDirection::ALL_DIRECTIONS {
             let expected = dir.direction_left().angle_pointy() - PI / 6.0;
-            assert!(dir.angle_pointy() - expected <= EPSILON);
+            assert!(dir.angle_pointy() - expected <= f32::EPSILON);
         }
     }
 
@@ -151,7 +150,7 @@ mod diagonal_direction {
     fn flat_angles_degrees() {
         for dir in DiagonalDirection::ALL_DIRECTIONS {
             let expected = dir.direction_left().angle_flat_degrees() - 30.0;
-            assert!(dir.angle_flat_degrees() - expected <= EPSILON);
+            assert!(dir.angle_flat_degrees() - expected <= f32::EPSILON);
         }
     }
 
@@ -159,7 +158,7 @@ mod diagonal_direction {
     fn pointy_angles_degrees() {
         for dir in DiagonalDirection::ALL_DIRECTIONS {
             let expected = dir.direction_left().angle_pointy_degrees() - 30.0;
-            assert!(dir.angle_pointy_degrees() - expected <= EPSILON);
+            assert!(dir.angle_pointy_degrees() - expected <= f32::EPSILON);
         }
     }
 }
@@ -0,0 +1,172 @@
+use crate::{Direction, Hex, HexVertex};
+
+/// Hexagonal edge coordinates, representing the border shared by two adjacent hexagons.
+///
+/// Useful for walls, rivers or doors, which live between two [`Hex`] coordinates rather than on
+/// them.
+///
+/// Every edge has a single *canonical* representation: an edge can be addressed from any of
+/// its two adjacent coordinates, the result will be the same.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let a = HexEdge::new(hex(3, 5), Direction::Bottom);
+/// let b = HexEdge::new(hex(3, 6), Direction::Top);
+/// assert_eq!(a, b);
+/// assert_eq!(a.hexes(), [hex(3, 6), hex(3, 5)]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "ser_de",
+    serde(from = "(Hex, Direction)", into = "(Hex, Direction)")
+)]
+pub struct HexEdge {
+    /// The coordinate from which the edge is addressed
+    hex: Hex,
+    /// The direction of the edge from `hex`, either `TopRight`, `Top` or `TopLeft`
+    direction: Direction,
+}
+
+impl HexEdge {
+    #[inline]
+    #[must_use]
+    /// Instantiates the edge between `hex` and its neighbor in the given `direction`
+    pub const fn new(hex: Hex, direction: Direction) -> Self {
+        match direction {
+            Direction::TopRight | Direction::Top | Direction::TopLeft => Self { hex, direction },
+            Direction::BottomLeft | Direction::Bottom | Direction::BottomRight => Self {
+                hex: hex.neighbor(direction),
+                direction: direction.const_neg(),
+            },
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the canonical coordinate from which the edge is addressed.
+    ///
+    /// See [`Self::direction`]
+    pub const fn hex(self) -> Hex {
+        self.hex
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the canonical direction of the edge from [`Self::hex`], which is either
+    /// [`Direction::TopRight`], [`Direction::Top`] or [`Direction::TopLeft`]
+    pub const fn direction(self) -> Direction {
+        self.direction
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the two coordinates sharing the edge
+    pub const fn hexes(self) -> [Hex; 2] {
+        [self.hex, self.hex.neighbor(self.direction)]
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the direction of the edge from `hex`, or `None` if the edge isn't a border of
+    /// `hex`
+    pub fn direction_from(self, hex: Hex) -> Option<Direction> {
+        match hex {
+            h if h == self.hex => Some(self.direction),
+            h if h == self.hex.neighbor(self.direction) => Some(self.direction.const_neg()),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the two vertices ending the edge, sorted counter clockwise around [`Self::hex`]
+    pub const fn vertices(self) -> [HexVertex; 2] {
+        [
+            HexVertex::new(self.hex, self.direction.right()),
+            HexVertex::new(self.hex, self.direction),
+        ]
+    }
+
+    #[must_use]
+    /// Returns the 4 edges sharing an end with `self`.
+    ///
+    /// The first two edges share the counter clockwise end of `self` (from [`Self::hex`]),
+    /// the last two share the clockwise end.
+    pub const fn adjacent_edges(self) -> [Self; 4] {
+        let (hex, dir) = (self.hex, self.direction);
+        let other = hex.neighbor(dir);
+        [
+            Self::new(hex, dir.left()),
+            Self::new(other, dir.rotate_left(2)),
+            Self::new(hex, dir.right()),
+            Self::new(other, dir.rotate_right(2)),
+        ]
+    }
+
+    #[must_use]
+    /// Returns all 6 edges of `hex`, following [`Direction`] order
+    pub fn all_edges(hex: Hex) -> [Self; 6] {
+        Direction::ALL_DIRECTIONS.map(|dir| Self::new(hex, dir))
+    }
+}
+
+impl From<(Hex, Direction)> for HexEdge {
+    #[inline]
+    fn from((hex, direction): (Hex, Direction)) -> Self {
+        Self::new(hex, direction)
+    }
+}
+
+impl From<HexEdge> for (Hex, Direction) {
+    #[inline]
+    fn from(edge: HexEdge) -> Self {
+        (edge.hex, edge.direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical() {
+        for hex in Hex::new(4, -7).range(5) {
+            for dir in Direction::iter() {
+                let edge = HexEdge::new(hex, dir);
+                assert_eq!(edge, HexEdge::new(hex.neighbor(dir), -dir));
+                assert!(edge.hexes().contains(&hex));
+                assert!(edge.hexes().contains(&hex.neighbor(dir)));
+                assert_eq!(edge.direction_from(hex), Some(dir));
+                assert_eq!(edge.direction_from(hex.neighbor(dir)), Some(-dir));
+                assert_eq!(edge.direction_from(hex.neighbor(dir.left())), None);
+            }
+        }
+    }
+
+    #[test]
+    fn adjacent_edges() {
+        let edge = HexEdge::new(Hex::ZERO, Direction::Top);
+        let adjacent = edge.adjacent_edges();
+        for other in adjacent {
+            assert_ne!(other, edge);
+            // Adjacent edges are borders of one of the edge hexes or of their common neighbors
+            let [a, b] = other.hexes();
+            assert!(a.unsigned_distance_to(Hex::ZERO) <= 1);
+            assert!(b.unsigned_distance_to(Hex::ZERO) <= 1);
+            // Adjacency is symmetrical
+            assert!(other.adjacent_edges().contains(&edge));
+        }
+        assert_eq!(
+            adjacent,
+            [
+                HexEdge::new(Hex::ZERO, Direction::TopLeft),
+                HexEdge::new(Hex::new(0, -1), Direction::BottomLeft),
+                HexEdge::new(Hex::ZERO, Direction::TopRight),
+                HexEdge::new(Hex::new(0, -1), Direction::BottomRight),
+            ]
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use super::Hex;
+
+impl Hex {
+    #[inline]
+    #[must_use]
+    /// Checked addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// assert_eq!(hex(1, 2).checked_add(hex(3, 4)), Some(hex(4, 6)));
+    /// assert_eq!(hex(i32::MAX, 0).checked_add(hex(1, 0)), None);
+    /// ```
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match (self.x.checked_add(rhs.x), self.y.checked_add(rhs.y)) {
+            (Some(x), Some(y)) => Some(Self::new(x, y)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match (self.x.checked_sub(rhs.x), self.y.checked_sub(rhs.y)) {
+            (Some(x), Some(y)) => Some(Self::new(x, y)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+    pub const fn checked_mul(self, rhs: i32) -> Option<Self> {
+        match (self.x.checked_mul(rhs), self.y.checked_mul(rhs)) {
+            (Some(x), Some(y)) => Some(Self::new(x, y)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checked negation. Computes `-self`, returning `None` if overflow occurred.
+    pub const fn checked_neg(self) -> Option<Self> {
+        match (self.x.checked_neg(), self.y.checked_neg()) {
+            (Some(x), Some(y)) => Some(Self::new(x, y)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checked `z` coordinate computation, returning `None` if overflow occurred.
+    ///
+    /// See [`Self::z`]
+    pub const fn checked_z(self) -> Option<i32> {
+        match self.x.checked_neg() {
+            Some(x) => x.checked_sub(self.y),
+            None => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Saturating addition. Computes `self + rhs`, saturating at the numeric bounds instead of
+    /// overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// assert_eq!(hex(i32::MAX, 0).saturating_add(hex(1, 1)), hex(i32::MAX, 1));
+    /// ```
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self::new(self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Saturating subtraction. Computes `self - rhs`, saturating at the numeric bounds instead
+    /// of overflowing.
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self::new(self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Saturating multiplication. Computes `self * rhs`, saturating at the numeric bounds
+    /// instead of overflowing.
+    pub const fn saturating_mul(self, rhs: i32) -> Self {
+        Self::new(self.x.saturating_mul(rhs), self.y.saturating_mul(rhs))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Saturating negation. Computes `-self`, saturating at the numeric bounds instead of
+    /// overflowing.
+    pub const fn saturating_neg(self) -> Self {
+        Self::new(self.x.saturating_neg(), self.y.saturating_neg())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the numeric
+    /// bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// assert_eq!(hex(i32::MAX, 0).wrapping_add(hex(1, 1)), hex(i32::MIN, 1));
+    /// ```
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self::new(self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the numeric
+    /// bounds.
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::new(self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the numeric
+    /// bounds.
+    pub const fn wrapping_mul(self, rhs: i32) -> Self {
+        Self::new(self.x.wrapping_mul(rhs), self.y.wrapping_mul(rhs))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the numeric bounds.
+    pub const fn wrapping_neg(self) -> Self {
+        Self::new(self.x.wrapping_neg(), self.y.wrapping_neg())
+    }
+
+    #[must_use]
+    /// Checked length computation, returning `None` if overflow occurred.
+    ///
+    /// See [`Self::length`]
+    pub fn checked_length(self) -> Option<i32> {
+        let z = self.checked_z()?;
+        let [x, y, z] = [
+            self.x.checked_abs()?,
+            self.y.checked_abs()?,
+            z.checked_abs()?,
+        ];
+        Some(x.max(y).max(z))
+    }
+
+    #[must_use]
+    /// Checked distance computation, returning `None` if overflow occurred.
+    ///
+    /// See [`Self::distance_to`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// assert_eq!(hex(0, 0).checked_distance_to(hex(3, -1)), Some(3));
+    /// assert_eq!(hex(i32::MIN, 0).checked_distance_to(hex(i32::MAX, 0)), None);
+    /// ```
+    pub fn checked_distance_to(self, rhs: Self) -> Option<i32> {
+        self.checked_sub(rhs)?.checked_length()
+    }
+
+    /// Checks that every coordinate within `range` of `self` can be represented, returning the
+    /// signed `range`
+    fn checked_radius(self, range: u32) -> Option<i32> {
+        let radius = i32::try_from(range).ok()?;
+        self.checked_add(Self::new(radius, radius))?;
+        self.checked_sub(Self::new(radius, radius))?;
+        // `z` must be representable for the coordinates at the edges of the range
+        let z = i64::from(self.x) + i64::from(self.y);
+        let z_range = -i64::from(i32::MAX)..=-i64::from(i32::MIN);
+        (z_range.contains(&(z - i64::from(radius))) && z_range.contains(&(z + i64::from(radius))))
+            .then_some(radius)
+    }
+
+    #[must_use]
+    /// Retrieves all [`Hex`] around `self` in a given `range`, returning `None` if any of the
+    /// coordinates would overflow.
+    ///
+    /// See [`Self::range`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// assert_eq!(hex(0, 0).checked_range(2).map(|r| r.len()), Some(19));
+    /// assert!(hex(i32::MAX - 1, 0).checked_range(2).is_none());
+    /// ```
+    pub fn checked_range(self, range: u32) -> Option<impl ExactSizeIterator<Item = Self>> {
+        self.checked_radius(range)?;
+        Some(self.range(range))
+    }
+
+    #[must_use]
+    /// Retrieves one [`Hex`] ring around `self` in a given `range`, returning `None` if any of
+    /// the coordinates would overflow.
+    ///
+    /// See [`Self::ring`]
+    pub fn checked_ring(self, range: u32) -> Option<impl ExactSizeIterator<Item = Self>> {
+        self.checked_radius(range)?;
+        Some(self.ring(range))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_ops() {
+        let max = Hex::new(i32::MAX, i32::MAX);
+        let min = Hex::new(i32::MIN, i32::MIN);
+        assert_eq!(max.checked_add(Hex::ONE), None);
+        assert_eq!(min.checked_sub(Hex::ONE), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(max.checked_z(), None);
+        assert_eq!(Hex::new(3, 4).checked_z(), Some(-7));
+        assert_eq!(max.saturating_add(Hex::ONE), max);
+        assert_eq!(min.saturating_sub(Hex::ONE), min);
+        assert_eq!(max.saturating_mul(-2), min);
+        assert_eq!(min.saturating_neg(), max);
+        assert_eq!(max.wrapping_add(Hex::ONE), min);
+        assert_eq!(min.wrapping_sub(Hex::ONE), max);
+        assert_eq!(min.wrapping_neg(), min);
+        assert_eq!(Hex::new(2, -3).wrapping_mul(3), Hex::new(6, -9));
+    }
+
+    #[test]
+    fn checked_range() {
+        let center = Hex::new(i32::MAX - 5, -5);
+        assert_eq!(center.checked_range(5).map(Iterator::count), Some(91));
+        assert!(center.checked_range(6).is_none());
+        assert_eq!(center.checked_ring(5).map(Iterator::count), Some(30));
+        assert!(center.checked_ring(6).is_none());
+        assert!(Hex::ZERO.checked_range(u32::MAX).is_none());
+        // Only `z` overflows
+        let center = Hex::new(i32::MIN / 2, i32::MIN / 2 + 3);
+        assert!(center.checked_range(2).is_some());
+        assert!(center.checked_range(3).is_none());
+        for hex in center.checked_range(2).unwrap() {
+            assert!(hex.checked_z().is_some());
+        }
+    }
+}
@@ -24,8 +24,8 @@ impl From<(f32, f32)> for Hex {
 
 impl From<[f32; 2]> for Hex {
     #[inline]
-    fn from([x, y]: [f32; 2]) -> Self {
-        Self::round((x, y))
+    fn from(a: [f32; 2]) -> Self {
+        Self::round(a.into())
     }
 }
 
@@ -0,0 +1,305 @@
+use crate::Hex;
+use glam::{Vec2, Vec3};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Hexagonal [axial] coordinates with floating point precision.
+///
+/// Unlike [`Hex`] which represents a single hexagon, a [`FractionalHex`] represents any point
+/// in hexagonal space, which is useful for smooth movement and interpolation between
+/// hexagons.
+/// Use [`Self::round`] to retrieve the [`Hex`] containing the point.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let a = FractionalHex::from(hex(0, 0));
+/// let b = FractionalHex::from(hex(4, -2));
+/// let middle = a.lerp(b, 0.5);
+/// assert_eq!(middle, FractionalHex::new(2.0, -1.0));
+/// assert_eq!(a.lerp(b, 0.4).round(), hex(2, -1));
+/// ```
+///
+/// [axial]: https://www.redblobgames.com/grids/hexagons/#coordinates-axial
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "packed", repr(C))]
+#[doc(alias = "HexF")]
+pub struct FractionalHex {
+    /// `x` axial coordinate (sometimes called `q` or `i`)
+    pub x: f32,
+    /// `y` axial coordinate (sometimes called `r` or `j`)
+    pub y: f32,
+}
+
+impl FractionalHex {
+    /// (0, 0)
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    #[inline]
+    #[must_use]
+    /// Instantiates new fractional coordinates from axial coordinates
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Instantiates new fractional coordinates from cubic coordinates.
+    ///
+    /// The `z` coordinate is ignored and recomputed from `x` and `y`
+    pub const fn from_cubic([x, y, _z]: [f32; 3]) -> Self {
+        Self::new(x, y)
+    }
+
+    #[inline]
+    #[must_use]
+    /// `z` cubic coordinate, computed from `x` and `y`
+    pub fn z(self) -> f32 {
+        -self.x - self.y
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts `self` to cubic coordinates
+    pub fn to_cubic_array(self) -> [f32; 3] {
+        [self.x, self.y, self.z()]
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts `self` to a [`Vec2`]
+    pub const fn as_vec2(self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts `self` to a cubic [`Vec3`]
+    pub fn as_vec3(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Rounds `self` to the [`Hex`] containing it.
+    ///
+    /// See [`Hex::round`]
+    pub fn round(self) -> Hex {
+        Hex::round((self.x, self.y))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Computes the distance from the origin in hexagonal space
+    pub fn length(self) -> f32 {
+        (self.x.abs() + self.y.abs() + self.z().abs()) / 2.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Computes the distance from `self` to `rhs` in hexagonal space
+    pub fn distance_to(self, rhs: Self) -> f32 {
+        (self - rhs).length()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Performs a linear interpolation between `self` and `rhs` based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
+    /// will be equal to `rhs`. When `s` is outside of range `[0, 1]`, the result is linearly
+    /// extrapolated.
+    pub fn lerp(self, rhs: Self, s: f32) -> Self {
+        self.as_vec2().lerp(rhs.as_vec2(), s).into()
+    }
+}
+
+impl Hex {
+    #[inline]
+    #[must_use]
+    /// Converts `self` to [`FractionalHex`] coordinates
+    pub const fn as_fractional(self) -> FractionalHex {
+        let v = self.as_vec2();
+        FractionalHex::new(v.x, v.y)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Performs a linear interpolation between `self` and `rhs` based on the value `s`, without
+    /// rounding the result.
+    ///
+    /// See [`Self::lerp`] for the rounded version
+    pub fn fractional_lerp(self, rhs: Self, s: f32) -> FractionalHex {
+        self.as_fractional().lerp(rhs.as_fractional(), s)
+    }
+}
+
+impl From<Hex> for FractionalHex {
+    #[inline]
+    fn from(hex: Hex) -> Self {
+        hex.as_fractional()
+    }
+}
+
+impl From<Vec2> for FractionalHex {
+    #[inline]
+    fn from(v: Vec2) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<FractionalHex> for Vec2 {
+    #[inline]
+    fn from(hex: FractionalHex) -> Self {
+        hex.as_vec2()
+    }
+}
+
+impl From<(f32, f32)> for FractionalHex {
+    #[inline]
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<FractionalHex> for Hex {
+    #[inline]
+    fn from(hex: FractionalHex) -> Self {
+        hex.round()
+    }
+}
+
+impl Add for FractionalHex {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Add<Hex> for FractionalHex {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Hex) -> Self::Output {
+        self + rhs.as_fractional()
+    }
+}
+
+impl AddAssign for FractionalHex {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for FractionalHex {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Sub<Hex> for FractionalHex {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Hex) -> Self::Output {
+        self - rhs.as_fractional()
+    }
+}
+
+impl SubAssign for FractionalHex {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<f32> for FractionalHex {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl MulAssign<f32> for FractionalHex {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<f32> for FractionalHex {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl DivAssign<f32> for FractionalHex {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for FractionalHex {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Sum for FractionalHex {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance() {
+        let a = FractionalHex::new(0.5, 0.0);
+        assert!((a.length() - 0.5).abs() < f32::EPSILON);
+        for h in Hex::new(2, -3).range(5) {
+            let [a, b] = [h.as_fractional(), Hex::ZERO.as_fractional()];
+            #[allow(clippy::cast_precision_loss)]
+            let expected = h.unsigned_distance_to(Hex::ZERO) as f32;
+            assert!((a.distance_to(b) - expected).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn round_and_lerp() {
+        let a = Hex::new(-3, 2);
+        let b = Hex::new(5, -7);
+        for i in 0..=10 {
+            #[allow(clippy::cast_precision_loss)]
+            let s = i as f32 / 10.0;
+            assert_eq!(a.fractional_lerp(b, s).round(), a.lerp(b, s));
+        }
+        let p = FractionalHex::new(1.2, -0.1);
+        assert_eq!(p.round(), Hex::new(1, 0));
+        assert!((p.z() + 1.1).abs() < f32::EPSILON);
+        assert_eq!(FractionalHex::from_cubic(p.to_cubic_array()), p);
+        assert_eq!(p + Hex::X - Hex::X, p);
+        assert_eq!(-p * 2.0 / 2.0, -p);
+    }
+}
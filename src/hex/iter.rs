@@ -67,7 +67,8 @@ impl<I: Iterator<Item = Hex>> HexIterExt for I {
     }
 }
 
-/// Private container for a [`Hex`] [`Iterator`] of known size
+/// Private container for a [`Hex`] [`Iterator`] of known size, also used by the sized
+/// coordinates
 #[derive(Debug, Clone)]
 pub struct ExactSizeHexIterator<I> {
     /// The inner iterator
@@ -78,9 +79,9 @@ pub struct ExactSizeHexIterator<I> {
 
 impl<I> Iterator for ExactSizeHexIterator<I>
 where
-    I: Iterator<Item = Hex>,
+    I: Iterator,
 {
-    type Item = Hex;
+    type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.count = self.count.saturating_sub(1);
@@ -92,4 +93,4 @@ where
     }
 }
 
-impl<I> ExactSizeIterator for ExactSizeHexIterator<I> where I: Iterator<Item = Hex> {}
+impl<I> ExactSizeIterator for ExactSizeHexIterator<I> where I: Iterator {}
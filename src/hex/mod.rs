@@ -1,6 +1,10 @@
 #![allow(clippy::inline_always)]
+/// Overflow safe arithmetic
+mod checked;
 /// Type conversions
 mod convert;
+/// Fractional coordinates
+mod fractional;
 /// Traits implementations
 mod impls;
 /// Iterator tools module
@@ -9,15 +13,20 @@ mod iter;
 mod rings;
 /// swizzle utils
 mod siwzzle;
+/// Hexagonal coordinates with various integer widths
+mod sized;
 #[cfg(test)]
 mod tests;
 
+pub use fractional::FractionalHex;
 pub(crate) use iter::ExactSizeHexIterator;
 pub use iter::HexIterExt;
+pub use sized::{Hex16, Hex64, Hex8};
 
 use crate::{DiagonalDirection, Direction, DirectionWay};
 use glam::{IVec2, IVec3, Vec2};
 use std::cmp::{max, min};
+use std::collections::HashSet;
 
 /// Hexagonal [axial] coordinates
 ///
@@ -38,8 +47,10 @@ use std::cmp::{max, min};
 /// # Conversions
 ///
 ///  * Cubic: use [`Self::z`] to compute the third axis
-///  * Offset: use [`Self::from_offset_coordinates`] and [`Self::to_offset_coordinates`]
-///  * Doubled: use [`Self::from_doubled_coordinates`] and [`Self::to_doubled_coordinates`]
+///  * Offset: use [`Self::from_offset_coordinates`] and [`Self::to_offset_coordinates`], or
+///    the [`OffsetHex`](crate::OffsetHex) type
+///  * Doubled: use [`Self::from_doubled_coordinates`] and [`Self::to_doubled_coordinates`], or
+///    the [`DoubledHex`](crate::DoubledHex) type
 ///
 /// [comparison]: https://www.redblobgames.com/grids/hexagons/#coordinates-comparison
 /// [axial]: https://www.redblobgames.com/grids/hexagons/#coordinates-axial
@@ -633,7 +644,7 @@ impl Hex {
     /// Find in which [`DiagonalDirection`] wedge `rhs` is relative to `self`.
     ///
     /// > This method can be innaccurate in case of a *tie* between directions, prefer
-    /// [`Self::diagonal_way_to`] instead
+    /// > [`Self::diagonal_way_to`] instead
     pub fn diagonal_to(self, rhs: Self) -> DiagonalDirection {
         self.main_diagonal_to(rhs)
     }
@@ -642,7 +653,7 @@ impl Hex {
     /// Find in which [`DiagonalDirection`] wedge `rhs` is relative to `self`.
     ///
     /// > This method can be innaccurate in case of a *tie* between directions, prefer
-    /// using [`Self::diagonal_way_to`] instead
+    /// > using [`Self::diagonal_way_to`] instead
     pub fn main_diagonal_to(self, rhs: Self) -> DiagonalDirection {
         self.diagonal_way_to(rhs).unwrap()
     }
@@ -671,7 +682,7 @@ impl Hex {
     /// Find in which [`Direction`] wedge `rhs` is relative to `self`
     ///
     /// > This method can be innaccurate in case of a *tie* between directions, prefer
-    /// using [`Self::way_to`] instead
+    /// > using [`Self::way_to`] instead
     pub fn direction_to(self, rhs: Self) -> Direction {
         self.main_direction_to(rhs)
     }
@@ -679,7 +690,7 @@ impl Hex {
     /// Find in which [`Direction`] wedge `rhs` is relative to `self`
     ///
     /// > This method can be innaccurate in case of a *tie* between directions, prefer
-    /// using [`Self::way_to`] for accuracy
+    /// > using [`Self::way_to`] for accuracy
     #[must_use]
     pub fn main_direction_to(self, rhs: Self) -> Direction {
         self.way_to(rhs).unwrap()
@@ -851,11 +862,74 @@ impl Hex {
         }
     }
 
+    #[must_use]
+    /// Computes all coordinates in a line from `self` to `other` using only integer arithmetic.
+    ///
+    /// Contrary to [`Self::line_to`], which relies on `f32` rounding, this method is fully
+    /// deterministic across platforms and suitable for *lockstep* simulations.
+    /// It yields the same coordinates as the geometric line, with ties (when the line passes
+    /// exactly on a hexagon edge) broken as described in [`Self::integer_lerp`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use hexx::*;
+    /// let start = Hex::ZERO;
+    /// let end = Hex::new(5, 0);
+    ///
+    /// let line = start.integer_line_to(end);
+    /// assert_eq!(line.len(), 6);
+    /// let line: Vec<Hex> = line.collect();
+    /// assert_eq!(line, start.line_to(end).collect::<Vec<_>>());
+    /// ```
+    pub fn integer_line_to(self, other: Self) -> impl ExactSizeIterator<Item = Self> {
+        let distance = self.unsigned_distance_to(other);
+        let steps = distance.max(1);
+        ExactSizeHexIterator {
+            iter: (0..=distance).map(move |step| self.integer_lerp(other, step, steps)),
+            count: distance as usize + 1,
+        }
+    }
+
+    #[must_use]
+    /// Computes all coordinates in a *thick* line from `self` to `other`, including every
+    /// coordinate within `radius` of the line. The resulting shape is sometimes called a *capsule*.
+    ///
+    /// The coordinates are returned without duplicates, in order along the line: each coordinate
+    /// comes with the first [`Self::line_to`] step within `radius` of it, and coordinates of a
+    /// same step are sorted by their distance to that step, following [`Self::spiral_range`].
+    /// A `radius` of 0 yields the same coordinates as [`Self::line_to`].
+    ///
+    /// The coordinates are computed eagerly, to remove duplicates and give the exact length.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use hexx::*;
+    /// let start = Hex::ZERO;
+    /// let end = Hex::new(5, 0);
+    ///
+    /// let line: Vec<Hex> = start.thick_line_to(end, 1).collect();
+    /// assert_eq!(line.len(), 22);
+    /// ```
+    pub fn thick_line_to(self, other: Self, radius: u32) -> impl ExactSizeIterator<Item = Self> {
+        let mut visited = HashSet::new();
+        let coords: Vec<_> = self
+            .line_to(other)
+            .flat_map(|h| h.spiral_range(0..=radius))
+            .filter(|h| visited.insert(*h))
+            .collect();
+        ExactSizeHexIterator {
+            count: coords.len(),
+            iter: coords.into_iter(),
+        }
+    }
+
     /// Performs a linear interpolation between `self` and `rhs` based on the value `s`.
     ///
     /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
     /// will be equal to `rhs`. When `s` is outside of range `[0, 1]`, the result is linearly
     /// extrapolated.
+    ///
+    /// See [`Self::integer_lerp`] for a deterministic, float-free version.
     #[doc(alias = "mix")]
     #[inline]
     #[must_use]
@@ -864,6 +938,42 @@ impl Hex {
         start.lerp(end, s).into()
     }
 
+    /// Performs a linear interpolation between `self` and `rhs` at the ratio
+    /// `numerator / denominator`, using only integer arithmetic.
+    ///
+    /// The interpolated cubic coordinates are rounded to the nearest integer, with halves
+    /// rounded up. The coordinate with the largest rounding error is then recomputed from the
+    /// two others, the `x` axis taking priority over `y`, and `y` over `z`, in case of a tie.
+    /// This fixed tie-breaking makes the result deterministic across platforms.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `denominator` is zero or if the interpolated coordinates don't fit in `i32`,
+    /// which may only happen when extrapolating (`numerator > denominator`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let a = Hex::ZERO;
+    /// let b = Hex::new(10, -4);
+    /// assert_eq!(a.integer_lerp(b, 0, 2), a);
+    /// assert_eq!(a.integer_lerp(b, 1, 2), Hex::new(5, -2));
+    /// assert_eq!(a.integer_lerp(b, 2, 2), b);
+    /// ```
+    #[must_use]
+    pub fn integer_lerp(self, rhs: Self, numerator: u32, denominator: u32) -> Self {
+        let delta = [
+            i128::from(rhs.x) - i128::from(self.x),
+            i128::from(rhs.y) - i128::from(self.y),
+        ];
+        let [x, y] = integer_lerp_offset(delta, numerator.into(), denominator.into());
+        let coord = |start: i32, offset: i128| {
+            i32::try_from(i128::from(start) + offset).expect(INTEGER_LERP_OVERFLOW)
+        };
+        Self::new(coord(self.x, x), coord(self.y, y))
+    }
+
     #[allow(clippy::cast_possible_wrap)]
     #[must_use]
     /// Retrieves all [`Hex`] around `self` in a given `range`.
@@ -955,3 +1065,41 @@ impl Hex {
         ]
     }
 }
+
+/// Panic message of integer interpolation overflows
+const INTEGER_LERP_OVERFLOW: &str = "interpolated coordinate overflow";
+
+/// Interpolates the axial vector `delta` at the ratio `numerator / denominator`, returning the
+/// rounded axial offset, following the [`Hex::integer_lerp`] rounding rules.
+///
+/// This is shared by [`Hex`] and the sized coordinates to keep the same tie-breaking.
+///
+/// # Panics
+///
+/// Will panic if `denominator` is zero or if the computation overflows `i128`
+fn integer_lerp_offset(delta: [i128; 2], numerator: u64, denominator: u64) -> [i128; 2] {
+    assert!(denominator != 0);
+    let [num, den] = [i128::from(numerator), i128::from(denominator)];
+    // Interpolated values are doubled, to round halves without fractions
+    let double = |v: i128| v.checked_mul(num).and_then(|v| v.checked_mul(2));
+    let [x, y, z] =
+        [delta[0], delta[1], -delta[0] - delta[1]].map(|v| double(v).expect(INTEGER_LERP_OVERFLOW));
+    // Rounds `v / (2 * den)` to the nearest integer, halves are rounded up
+    let round = |v: i128| {
+        v.checked_add(den)
+            .expect(INTEGER_LERP_OVERFLOW)
+            .div_euclid(2 * den)
+    };
+    let [mut rx, mut ry, rz] = [round(x), round(y), round(z)];
+    let [dx, dy, dz] = [
+        (x - rx * 2 * den).abs(),
+        (y - ry * 2 * den).abs(),
+        (z - rz * 2 * den).abs(),
+    ];
+    if dx >= dy && dx >= dz {
+        rx = -ry - rz;
+    } else if dy >= dz {
+        ry = -rx - rz;
+    }
+    [rx, ry]
+}
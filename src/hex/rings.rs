@@ -32,7 +32,7 @@ impl Hex {
         let point = self + start_dir * range as i32;
         let iter = directions
             .into_iter()
-            .flat_map(move |dir| std::iter::repeat(dir).take(range as usize))
+            .flat_map(move |dir| std::iter::repeat_n(dir, range as usize))
             .scan(point, move |pos, dir| {
                 let next = *pos + dir;
                 *pos = next;
@@ -0,0 +1,566 @@
+use super::{integer_lerp_offset, ExactSizeHexIterator, INTEGER_LERP_OVERFLOW};
+use crate::{Direction, Hex};
+use std::{
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Generates an hexagonal coordinate type with a specific integer width, mirroring the
+/// core [`Hex`] API
+macro_rules! sized_hex {
+    ($(#[$meta:meta])* $name:ident, $int:ty, $uint:ty) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+        #[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "packed", repr(C))]
+        pub struct $name {
+            /// `x` axial coordinate (sometimes called `q` or `i`)
+            pub x: $int,
+            /// `y` axial coordinate (sometimes called `r` or `j`)
+            pub y: $int,
+        }
+
+        impl $name {
+            /// (0, 0)
+            pub const ZERO: Self = Self::new(0, 0);
+            /// (1, 0)
+            pub const X: Self = Self::new(1, 0);
+            /// (0, 1)
+            pub const Y: Self = Self::new(0, 1);
+            /// Hexagon neighbor coordinates array, following [`Direction`] order
+            pub const NEIGHBORS_COORDS: [Self; 6] = [
+                Self::new(1, -1),
+                Self::new(0, -1),
+                Self::new(-1, 0),
+                Self::new(-1, 1),
+                Self::new(0, 1),
+                Self::new(1, 0),
+            ];
+
+            #[inline]
+            #[must_use]
+            /// Instantiates a new hexagon from axial coordinates
+            pub const fn new(x: $int, y: $int) -> Self {
+                Self { x, y }
+            }
+
+            #[inline]
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+            #[doc = concat!(
+                "Converts `hex` to [`", stringify!($name), "`], like an `as` cast.\n\n",
+                "The coordinates are truncated if they don't fit in `", stringify!($int), "`, ",
+                "use [`TryFrom`] for a checked conversion"
+            )]
+            pub const fn from_hex(hex: Hex) -> Self {
+                Self::new(hex.x as $int, hex.y as $int)
+            }
+
+            #[inline]
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+            /// Converts `self` to [`Hex`], like an `as` cast.
+            ///
+            /// The coordinates are truncated if they don't fit in `i32`, use [`TryFrom`] or
+            /// [`From`] for a checked or lossless conversion
+            pub const fn as_hex(self) -> Hex {
+                Hex::new(self.x as i32, self.y as i32)
+            }
+
+            #[inline]
+            #[must_use]
+            /// `z` coordinate (sometimes called `s` or `k`).
+            ///
+            /// This cubic space coordinate is computed as `-x - y`
+            ///
+            /// # Panics
+            ///
+            /// Will panic in debug builds if `z` overflows, like for `(MIN, MIN)` coordinates.
+            /// See [`Self::checked_z`] for a checked version
+            pub const fn z(self) -> $int {
+                -self.x - self.y
+            }
+
+            #[inline]
+            #[must_use]
+            /// Checked `z` coordinate computation, returning `None` if overflow occurred.
+            ///
+            /// See [`Self::z`]
+            pub const fn checked_z(self) -> Option<$int> {
+                match self.x.checked_neg() {
+                    Some(x) => x.checked_sub(self.y),
+                    None => None,
+                }
+            }
+
+            #[inline]
+            #[must_use]
+            /// adds `self` and `other`.
+            ///
+            /// [`Self`] implements [`Add`] (`+` operator) but this method is `const`.
+            pub const fn const_add(self, other: Self) -> Self {
+                Self::new(self.x + other.x, self.y + other.y)
+            }
+
+            #[inline]
+            #[must_use]
+            /// substracts `self` and `rhs`.
+            ///
+            /// [`Self`] implements [`Sub`] (`-` operator) but this method is `const`.
+            pub const fn const_sub(self, rhs: Self) -> Self {
+                Self::new(self.x - rhs.x, self.y - rhs.y)
+            }
+
+            #[inline]
+            #[must_use]
+            /// Negates the coordinate, giving its reflection (symmetry) around the origin.
+            ///
+            /// [`Self`] implements [`Neg`] (`-` operator) but this method is `const`.
+            pub const fn const_neg(self) -> Self {
+                Self::new(-self.x, -self.y)
+            }
+
+            #[inline]
+            #[must_use]
+            #[allow(clippy::cast_possible_wrap)]
+            /// Computes coordinates length as a signed integer.
+            /// The length of a coordinate is equal to its distance from the origin.
+            ///
+            #[doc = concat!("The length saturates at `", stringify!($int), "::MAX`, ")]
+            /// see [`Self::checked_length`] for a checked version and [`Self::ulength`] for the
+            /// unsigned version
+            pub const fn length(self) -> $int {
+                let length = self.ulength();
+                if length > <$int>::MAX as $uint {
+                    <$int>::MAX
+                } else {
+                    length as $int
+                }
+            }
+
+            #[inline]
+            #[must_use]
+            #[doc(alias = "unsigned_length")]
+            #[allow(clippy::cast_possible_truncation)]
+            /// Computes coordinates length as an unsigned integer.
+            /// The length of a coordinate is equal to its distance from the origin.
+            ///
+            #[doc = concat!(
+                "The length saturates at `", stringify!($uint), "::MAX`, which is only reached ",
+                "when the `z` coordinate doesn't fit in `", stringify!($int), "`."
+            )]
+            ///
+            /// See [`Self::length`] for the signed version
+            pub const fn ulength(self) -> $uint {
+                // The cubic coordinates are computed with a wider integer, as `z` may overflow
+                let [x, y] = [self.x as i128, self.y as i128];
+                let [x, y, z] = [x.unsigned_abs(), y.unsigned_abs(), (-x - y).unsigned_abs()];
+                let max = if x >= y && x >= z {
+                    x
+                } else if y >= z {
+                    y
+                } else {
+                    z
+                };
+                if max > <$uint>::MAX as u128 {
+                    <$uint>::MAX
+                } else {
+                    max as $uint
+                }
+            }
+
+            #[must_use]
+            /// Checked length computation, returning `None` if overflow occurred.
+            ///
+            /// See [`Self::length`]
+            pub fn checked_length(self) -> Option<$int> {
+                let z = self.checked_z()?;
+                let [x, y, z] = [
+                    self.x.checked_abs()?,
+                    self.y.checked_abs()?,
+                    z.checked_abs()?,
+                ];
+                Some(x.max(y).max(z))
+            }
+
+            #[must_use]
+            /// Checked distance computation, returning `None` if overflow occurred.
+            ///
+            /// See [`Self::distance_to`]
+            pub fn checked_distance_to(self, rhs: Self) -> Option<$int> {
+                let delta = Self::new(self.x.checked_sub(rhs.x)?, self.y.checked_sub(rhs.y)?);
+                delta.checked_length()
+            }
+
+            #[inline]
+            #[must_use]
+            /// Computes the distance from `self` to `rhs` in hexagonal space as a signed integer
+            ///
+            /// See [`Self::unsigned_distance_to`] for the unsigned version
+            pub const fn distance_to(self, rhs: Self) -> $int {
+                self.const_sub(rhs).length()
+            }
+
+            #[inline]
+            #[must_use]
+            /// Computes the distance from `self` to `rhs` in hexagonal space as an unsigned integer
+            ///
+            /// See [`Self::distance_to`] for the signed version
+            pub const fn unsigned_distance_to(self, rhs: Self) -> $uint {
+                self.const_sub(rhs).ulength()
+            }
+
+            #[inline]
+            #[must_use]
+            /// Retrieves the hexagonal neighbor coordinates matching the given `direction`
+            pub const fn neighbor_coord(direction: Direction) -> Self {
+                Self::NEIGHBORS_COORDS[direction as usize]
+            }
+
+            #[inline]
+            #[must_use]
+            /// Retrieves the neighbor coordinates matching the given `direction`
+            pub const fn neighbor(self, direction: Direction) -> Self {
+                self.const_add(Self::neighbor_coord(direction))
+            }
+
+            #[inline]
+            #[must_use]
+            /// Retrieves all 6 neighbor coordinates around `self`, following [`Direction`] order
+            pub fn all_neighbors(self) -> [Self; 6] {
+                Self::NEIGHBORS_COORDS.map(|n| self.const_add(n))
+            }
+
+            #[allow(clippy::cast_possible_truncation)]
+            /// Retrieves one ring around `self` in a given `range`.
+            /// The returned coordinates start from [`Direction::TopRight`] and loop around `self`
+            /// counter clockwise, like [`Hex::ring`].
+            ///
+            /// The returned iterator will have `6 * range` items, unless `range` is 0 which will
+            /// return `self`
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!(
+                "Will panic if `range` doesn't fit in `", stringify!($int), "`, ",
+                "and in debug builds if the ring coordinates overflow"
+            )]
+            pub fn ring(self, range: $uint) -> impl ExactSizeIterator<Item = Self> {
+                let radius = <$int>::try_from(range).expect("ring radius overflow");
+                let mut directions = Self::NEIGHBORS_COORDS;
+                directions.rotate_left(2);
+                let point = self + Self::neighbor_coord(Direction::TopRight) * radius;
+                let iter = directions
+                    .into_iter()
+                    .flat_map(move |dir| std::iter::repeat_n(dir, range as usize))
+                    .scan(point, |pos, dir| {
+                        *pos += dir;
+                        Some(*pos)
+                    })
+                    .take((range as usize * 6).saturating_sub(1));
+                ExactSizeHexIterator {
+                    iter: std::iter::once(point).chain(iter),
+                    count: (range as usize * 6).max(1),
+                }
+            }
+
+            /// Computes all coordinates in a line from `self` to `other` using only integer
+            /// arithmetic, like [`Hex::integer_line_to`].
+            ///
+            /// The returned iterator will have `self.unsigned_distance_to(other) + 1` items
+            ///
+            /// # Panics
+            ///
+            /// See [`Self::integer_lerp`]
+            #[allow(clippy::cast_possible_truncation)]
+            pub fn integer_line_to(self, other: Self) -> impl ExactSizeIterator<Item = Self> {
+                let distance = self.unsigned_distance_to(other);
+                let steps = distance.max(1);
+                ExactSizeHexIterator {
+                    iter: (0..=distance).map(move |step| self.integer_lerp(other, step, steps)),
+                    count: distance as usize + 1,
+                }
+            }
+
+            #[must_use]
+            /// Performs a linear interpolation between `self` and `rhs` at the ratio
+            /// `numerator / denominator`, using only integer arithmetic.
+            ///
+            /// See [`Hex::integer_lerp`] for the rounding rules.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `denominator` is zero, or if the interpolated coordinates don't fit
+            #[doc = concat!("in `", stringify!($int), "`.")]
+            ///
+            /// The interpolation is computed with `i128` integers, which may also overflow for
+            /// [`Hex64`] coordinates and ratio terms close to their limits, in which case this
+            /// method panics as well.
+            pub fn integer_lerp(self, rhs: Self, numerator: $uint, denominator: $uint) -> Self {
+                let delta = [
+                    i128::from(rhs.x) - i128::from(self.x),
+                    i128::from(rhs.y) - i128::from(self.y),
+                ];
+                let [x, y] = integer_lerp_offset(delta, numerator.into(), denominator.into());
+                let coord = |start: $int, offset: i128| {
+                    <$int>::try_from(i128::from(start) + offset).expect(INTEGER_LERP_OVERFLOW)
+                };
+                Self::new(coord(self.x, x), coord(self.y, y))
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                self.const_add(rhs)
+            }
+        }
+
+        impl AddAssign<Direction> for $name {
+            #[inline]
+            fn add_assign(&mut self, rhs: Direction) {
+                *self = self.neighbor(rhs);
+            }
+        }
+
+        impl AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = self.const_add(rhs);
+            }
+        }
+
+        impl Add<Direction> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Direction) -> Self::Output {
+                self.neighbor(rhs)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.const_sub(rhs)
+            }
+        }
+
+        impl SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = self.const_sub(rhs);
+            }
+        }
+
+        impl Mul<$int> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: $int) -> Self::Output {
+                Self::new(self.x * rhs, self.y * rhs)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                self.const_neg()
+            }
+        }
+
+        impl From<($int, $int)> for $name {
+            #[inline]
+            fn from((x, y): ($int, $int)) -> Self {
+                Self::new(x, y)
+            }
+        }
+    };
+}
+
+/// Implements lossless [`From`] conversions from a narrower coordinate type to a wider one
+macro_rules! impl_widening {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl From<$from> for $to {
+                #[inline]
+                fn from(hex: $from) -> Self {
+                    Self::new(hex.x.into(), hex.y.into())
+                }
+            }
+        )*
+    };
+}
+
+/// Implements checked [`TryFrom`] conversions from a wider coordinate type to a narrower one
+macro_rules! impl_narrowing {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl TryFrom<$from> for $to {
+                type Error = TryFromIntError;
+
+                #[inline]
+                fn try_from(hex: $from) -> Result<Self, Self::Error> {
+                    Ok(Self::new(hex.x.try_into()?, hex.y.try_into()?))
+                }
+            }
+        )*
+    };
+}
+
+sized_hex!(
+    /// Hexagonal axial coordinates using `i8`, for compact storage and networking.
+    ///
+    /// This type mirrors the main [`Hex`] API for distances, neighbors, rings and lines.
+    /// Use [`From`] and [`TryFrom`] for lossless and checked conversions to other widths.
+    Hex8,
+    i8,
+    u8
+);
+sized_hex!(
+    /// Hexagonal axial coordinates using `i16`, for compact storage and networking.
+    ///
+    /// This type mirrors the main [`Hex`] API for distances, neighbors, rings and lines.
+    /// Use [`From`] and [`TryFrom`] for lossless and checked conversions to other widths.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let a = Hex16::new(-300, 12);
+    /// let hex = Hex::from(a);
+    /// assert_eq!(Hex16::try_from(hex), Ok(a));
+    /// assert!(Hex16::try_from(hex * 1000).is_err());
+    /// ```
+    Hex16,
+    i16,
+    u16
+);
+sized_hex!(
+    /// Hexagonal axial coordinates using `i64`, for huge worlds.
+    ///
+    /// This type mirrors the main [`Hex`] API for distances, neighbors, rings and lines.
+    /// Use [`From`] and [`TryFrom`] for lossless and checked conversions to other widths.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let a = Hex64::new(i64::from(i32::MAX) * 4, 0);
+    /// let b = Hex64::new(0, i64::from(i32::MIN) * 4);
+    /// assert_eq!(a.unsigned_distance_to(b), 4 * u64::from(u32::MAX));
+    /// assert!(Hex::try_from(a).is_err());
+    /// ```
+    Hex64,
+    i64,
+    u64
+);
+
+impl_widening!(
+    Hex8 => Hex16,
+    Hex8 => Hex,
+    Hex8 => Hex64,
+    Hex16 => Hex,
+    Hex16 => Hex64,
+    Hex => Hex64,
+);
+
+impl_narrowing!(
+    Hex16 => Hex8,
+    Hex => Hex8,
+    Hex => Hex16,
+    Hex64 => Hex8,
+    Hex64 => Hex16,
+    Hex64 => Hex,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_hex() {
+        let [a, b] = [Hex::new(-12, 31), Hex::new(25, -7)];
+        let [a16, b16] = [a, b].map(Hex16::from_hex);
+        let [a64, b64] = [a, b].map(Hex64::from);
+        assert_eq!(
+            u32::from(a16.unsigned_distance_to(b16)),
+            a.unsigned_distance_to(b)
+        );
+        assert_eq!(
+            a64.unsigned_distance_to(b64),
+            u64::from(a.unsigned_distance_to(b))
+        );
+        assert_eq!(i32::from(a16.distance_to(b16)), a.distance_to(b));
+        for dir in Direction::iter() {
+            assert_eq!(a16.neighbor(dir).as_hex(), a.neighbor(dir));
+            assert_eq!(a64.neighbor(dir).as_hex(), a.neighbor(dir));
+        }
+        for range in 0..5_u32 {
+            let ring: Vec<_> = a64.ring(range.into()).map(Hex64::as_hex).collect();
+            assert_eq!(ring, a.ring(range).collect::<Vec<_>>());
+        }
+        let line: Vec<_> = a16.integer_line_to(b16).map(Hex16::as_hex).collect();
+        assert_eq!(line, a.integer_line_to(b).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn length_limits() {
+        let min = Hex8::new(i8::MIN, i8::MIN);
+        assert_eq!(min.ulength(), u8::MAX);
+        assert_eq!(min.length(), i8::MAX);
+        assert_eq!(min.checked_length(), None);
+        assert_eq!(min.checked_z(), None);
+        assert_eq!(Hex8::new(i8::MIN, 0).checked_length(), None);
+        assert_eq!(Hex8::new(i8::MIN, 0).ulength(), 128);
+        assert_eq!(Hex8::new(-100, -27).checked_length(), Some(127));
+        let min = Hex64::new(i64::MIN, i64::MIN);
+        assert_eq!(min.ulength(), u64::MAX);
+        assert_eq!(min.length(), i64::MAX);
+        assert_eq!(min.checked_distance_to(Hex64::ZERO), None);
+        assert_eq!(Hex16::new(3, -1).checked_distance_to(Hex16::ZERO), Some(3));
+        assert_eq!(Hex16::ZERO.ring(3).len(), 18);
+        assert_eq!(Hex16::ZERO.ring(0).len(), 1);
+        assert_eq!(Hex16::ZERO.integer_line_to(Hex16::new(4, -1)).len(), 5);
+    }
+
+    #[test]
+    fn integer_lerp_limits() {
+        let [a, b] = [Hex64::new(i64::MIN / 4, 0), Hex64::new(i64::MAX / 4, 0)];
+        assert_eq!(a.integer_lerp(b, 1, 1), b);
+        assert_eq!(a.integer_lerp(b, 1, 2), Hex64::new(-1, 0));
+        let [a, b] = [Hex8::new(-100, 0), Hex8::new(100, 0)];
+        assert_eq!(a.integer_lerp(b, u8::MAX, u8::MAX), b);
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolated coordinate overflow")]
+    fn integer_lerp_overflow() {
+        let [a, b] = [Hex64::new(i64::MIN / 2, 0), Hex64::new(i64::MAX / 2, 0)];
+        let _ = a.integer_lerp(b, u64::MAX, u64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "ring radius overflow")]
+    fn ring_overflow() {
+        let _ = Hex8::ZERO.ring(200);
+    }
+
+    #[test]
+    fn conversions() {
+        let hex = Hex::new(100, -100);
+        assert_eq!(Hex8::try_from(hex), Ok(Hex8::new(100, -100)));
+        assert!(Hex8::try_from(hex * 2).is_err());
+        assert_eq!(Hex8::from_hex(hex * 2), Hex8::new(-56, 56));
+        assert_eq!(Hex64::from(Hex8::new(-1, 2)), Hex64::new(-1, 2));
+        assert_eq!(Hex::try_from(Hex64::new(-1, 2)), Ok(Hex::new(-1, 2)));
+        let huge = Hex64::new(i64::MAX / 4, i64::MIN / 4);
+        assert_eq!(huge.ulength(), (i64::MAX / 4) as u64 + 1);
+    }
+}
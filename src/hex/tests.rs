@@ -367,6 +367,107 @@ fn empty_line_to() {
     assert_eq!(line.collect::<Vec<_>>(), vec![start]);
 }
 
+#[test]
+fn integer_line_to() {
+    let a = Hex::new(0, 0);
+    for b in [
+        Hex::new(5, 0),
+        Hex::new(5, 5),
+        Hex::new(-3, 7),
+        Hex::new(12, -4),
+    ] {
+        let line = a.integer_line_to(b);
+        assert_eq!(line.len(), a.unsigned_distance_to(b) as usize + 1);
+        let line: Vec<_> = line.collect();
+        assert_eq!(line, a.line_to(b).collect::<Vec<_>>());
+    }
+    let start = Hex::new(3, -7);
+    assert_eq!(
+        start.integer_line_to(start).collect::<Vec<_>>(),
+        vec![start]
+    );
+}
+
+#[test]
+fn integer_line_is_continuous() {
+    let start = Hex::new(-4, 2);
+    for end in start.range(20) {
+        let line: Vec<_> = start.integer_line_to(end).collect();
+        assert_eq!(line.first(), Some(&start));
+        assert_eq!(line.last(), Some(&end));
+        for pair in line.windows(2) {
+            assert_eq!(pair[0].unsigned_distance_to(pair[1]), 1);
+        }
+    }
+}
+
+#[test]
+fn thick_line_to() {
+    let a = Hex::new(-3, 2);
+    let b = Hex::new(8, -5);
+    let line: Vec<_> = a.line_to(b).collect();
+    assert_eq!(a.thick_line_to(b, 0).collect::<Vec<_>>(), line);
+    for radius in 1..5 {
+        let iter = a.thick_line_to(b, radius);
+        let len = iter.len();
+        let thick: Vec<_> = iter.collect();
+        assert_eq!(thick.len(), len);
+        // Deterministic order
+        assert!(thick.iter().copied().eq(a.thick_line_to(b, radius)));
+        let unique: HashSet<_> = thick.iter().copied().collect();
+        // No duplicates
+        assert_eq!(unique.len(), thick.len());
+        // Every coordinate within `radius` of the line is included
+        let expected: HashSet<_> = a
+            .range(a.unsigned_distance_to(b) + radius)
+            .filter(|h| line.iter().any(|l| l.unsigned_distance_to(*h) <= radius))
+            .collect();
+        assert_eq!(unique, expected);
+        // Ordered by first line step within `radius`, then by distance to that step
+        let order: Vec<_> = thick
+            .iter()
+            .map(|h| {
+                let (step, l) = line
+                    .iter()
+                    .enumerate()
+                    .find(|(_, l)| l.unsigned_distance_to(*h) <= radius)
+                    .unwrap();
+                (step, l.unsigned_distance_to(*h))
+            })
+            .collect();
+        assert!(order.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(thick.first(), Some(&a));
+    }
+}
+
+#[test]
+fn integer_lerp() {
+    let a = Hex::new(0, 0);
+    let b = Hex::new(5, 0);
+    assert_eq!(a.integer_lerp(b, 0, 10), a);
+    assert_eq!(a.integer_lerp(b, 10, 10), b);
+    assert_eq!(a.integer_lerp(b, 20, 10), b * 2);
+    assert_eq!(a.integer_lerp(b, 4, 10), Hex::new(2, 0));
+    // Tie between `x` and `z`, `x` is recomputed
+    assert_eq!(a.integer_lerp(b, 3, 10), Hex::new(1, 0));
+    // No overflow on large coordinates
+    let a = Hex::new(-1_500_000_000, 0);
+    let b = Hex::new(1_500_000_000, 0);
+    assert_eq!(a.integer_lerp(b, 1, 2), Hex::ZERO);
+    let a = Hex::new(i32::MIN / 2, 0);
+    let b = Hex::new(i32::MAX / 2, 0);
+    assert_eq!(a.integer_lerp(b, u32::MAX, u32::MAX), b);
+    assert_eq!(a.integer_lerp(b, u32::MAX / 2, u32::MAX), Hex::new(-1, 0));
+}
+
+#[test]
+#[should_panic(expected = "interpolated coordinate overflow")]
+fn integer_lerp_overflow() {
+    let a = Hex::new(i32::MIN / 2, 0);
+    let b = Hex::new(i32::MAX / 2, 0);
+    let _ = a.integer_lerp(b, u32::MAX, 1);
+}
+
 #[test]
 fn range_count() {
     assert_eq!(Hex::range_count(0), 1);
@@ -1,5 +1,9 @@
-use crate::{Direction, Hex, HexOrientation};
+use crate::{
+    hex::ExactSizeHexIterator, DiagonalDirection, Direction, FractionalHex, Hex, HexEdge,
+    HexOrientation, HexRegion, HexVertex,
+};
 use glam::Vec2;
+use std::f32::consts::TAU;
 
 /// Hexagonal layout. This type is the bridge between your *world*/*pixel* coordinate system
 /// and the hexagonal coordinate system.
@@ -15,8 +19,10 @@ use glam::Vec2;
 ///     // We define the world space origin equivalent of `Hex::ZERO` in hex space
 ///     origin: Vec2::new(1.0, 2.0),
 ///     // We define the world space size of the hexagons
-///     hex_size: Vec2::new(1.0, 1.0)
-/// };
+///     hex_size: Vec2::new(1.0, 1.0),
+/// }
+/// // We can invert the world axis, for y-down screen coordinates or flipped engines
+/// .with_inverted_y(false);
 /// // You can now find the world positon (center) of any given hexagon
 /// let world_pos = layout.hex_to_world_pos(Hex::ZERO);
 /// // You can also find which hexagon is at a given world/screen position
@@ -33,29 +39,216 @@ pub struct HexLayout {
     pub hex_size: Vec2,
 }
 
+/// Barycentric position of a world/pixel point inside an hexagon, see
+/// [`HexLayout::world_pos_to_barycentric`].
+///
+/// An hexagon is split in six triangles, each made of its center and one of its edges.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexBarycentric {
+    /// The hexagon containing the point
+    pub hex: Hex,
+    /// The direction of the edge of the triangle containing the point
+    pub direction: Direction,
+    /// The barycentric weights of the point, relative to the hexagon center and to the two
+    /// edge ends, sorted counter clockwise around `hex` like [`HexLayout::edge_corners`].
+    /// The weights sum up to `1.0`
+    pub weights: [f32; 3],
+}
+
 impl HexLayout {
-    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    #[must_use]
+    /// Returns the world/pixel axis multipliers matching [`Self::invert_x`] and
+    /// [`Self::invert_y`]
+    pub(crate) const fn axis_scale(&self) -> Vec2 {
+        Vec2::new(
+            if self.orientation.invert_x { -1.0 } else { 1.0 },
+            if self.orientation.invert_y { -1.0 } else { 1.0 },
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if exactly one axis is inverted, which reverses the rotational order of
+    /// world/pixel positions, like the winding of [`Self::hex_corners`]
+    pub const fn is_mirrored(&self) -> bool {
+        self.orientation.invert_x != self.orientation.invert_y
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the world/pixel `x` axis is inverted (mirrored), see
+    /// [`Self::with_inverted_x`]
+    pub const fn invert_x(&self) -> bool {
+        self.orientation.invert_x
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the world/pixel `y` axis is inverted (mirrored), see
+    /// [`Self::with_inverted_y`]
+    pub const fn invert_y(&self) -> bool {
+        self.orientation.invert_y
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets [`Self::invert_x`], inverting (mirroring) the world/pixel `x` axis in all
+    /// conversions.
+    ///
+    /// The inversion is stored in [`Self::orientation`], replacing the orientation resets it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default().with_inverted_x(true);
+    /// assert!(layout.is_mirrored());
+    /// ```
+    pub const fn with_inverted_x(mut self, invert_x: bool) -> Self {
+        self.orientation.invert_x = invert_x;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets [`Self::invert_y`], inverting (mirroring) the world/pixel `y` axis in all
+    /// conversions, useful for y-down screen space.
+    ///
+    /// The inversion is stored in [`Self::orientation`], replacing the orientation resets it.
+    pub const fn with_inverted_y(mut self, invert_y: bool) -> Self {
+        self.orientation.invert_y = invert_y;
+        self
+    }
+
     #[must_use]
     /// Computes hexagonal coordinates `hex` into world/pixel coordinates
     pub fn hex_to_world_pos(&self, hex: Hex) -> Vec2 {
-        let matrix = self.orientation.forward_matrix;
-        Vec2::new(
-            matrix[0].mul_add(hex.x() as f32, matrix[1] * hex.y() as f32),
-            matrix[2].mul_add(hex.x() as f32, matrix[3] * hex.y() as f32),
-        ) * self.hex_size
-            + self.origin
+        self.fract_hex_to_world_pos(hex.as_fractional())
+    }
+
+    #[must_use]
+    /// Computes fractional hexagonal coordinates `hex` into world/pixel coordinates
+    pub fn fract_hex_to_world_pos(&self, hex: FractionalHex) -> Vec2 {
+        self.orientation.forward(hex.as_vec2()) * self.hex_size * self.axis_scale() + self.origin
     }
 
-    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
     #[must_use]
     /// Computes world/pixel coordinates `pos` into hexagonal coordinates
     pub fn world_pos_to_hex(&self, pos: Vec2) -> Hex {
-        let matrix = self.orientation.inverse_matrix;
-        let point = (pos - self.origin) / self.hex_size;
-        Hex::round((
-            matrix[0].mul_add(point.x, matrix[1] * point.y),
-            matrix[2].mul_add(point.x, matrix[3] * point.y),
-        ))
+        self.world_pos_to_fract_hex(pos).round()
+    }
+
+    #[must_use]
+    /// Computes the edge closest to the world/pixel coordinates `pos`, useful to snap
+    /// positions to the grid edges.
+    ///
+    /// The edge can be converted to its `(Hex, Direction)` pair with [`Into`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let edge = layout.world_pos_to_edge(Vec2::new(0.0, -0.8));
+    /// assert_eq!(edge, HexEdge::new(Hex::ZERO, Direction::Top));
+    /// ```
+    pub fn world_pos_to_edge(&self, pos: Vec2) -> HexEdge {
+        // The closest edge is always an edge of the containing hexagon
+        let hex = self.world_pos_to_hex(pos);
+        let distance = |dir: &Direction| {
+            let [a, b] = self.edge_corners(HexEdge::new(hex, *dir));
+            crate::raster::point_segment_distance(pos, a, b)
+        };
+        let dir = Direction::ALL_DIRECTIONS
+            .into_iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+            .unwrap_or_default();
+        HexEdge::new(hex, dir)
+    }
+
+    #[must_use]
+    /// Computes the vertex closest to the world/pixel coordinates `pos`, among the corners
+    /// of its containing hexagon, useful to snap positions to the grid vertices.
+    ///
+    /// The vertex can be converted to its `(Hex, Direction)` pair with [`Into`], and its corner
+    /// direction is given by [`HexVertex::diagonal_direction`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let vertex = layout.world_pos_to_vertex(Vec2::new(0.9, 0.1));
+    /// assert_eq!(vertex, HexVertex::from_diagonal(Hex::ZERO, DiagonalDirection::Right));
+    /// ```
+    pub fn world_pos_to_vertex(&self, pos: Vec2) -> HexVertex {
+        let hex = self.world_pos_to_hex(pos);
+        let distance =
+            |diagonal: &DiagonalDirection| self.hex_corner(hex, *diagonal).distance_squared(pos);
+        let diagonal = DiagonalDirection::ALL_DIRECTIONS
+            .into_iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+            .unwrap_or_default();
+        HexVertex::from_diagonal(hex, diagonal)
+    }
+
+    #[must_use]
+    /// Computes the barycentric position of the world/pixel coordinates `pos` in its
+    /// containing hexagon: the triangle, made of the hexagon center and one of its edges,
+    /// containing `pos` and the weights of `pos` in it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let edge = HexEdge::new(Hex::ZERO, Direction::Top);
+    /// let bary = layout.world_pos_to_barycentric(layout.edge_to_world_pos(edge));
+    /// assert_eq!(bary.hex, Hex::ZERO);
+    /// assert_eq!(bary.direction, Direction::Top);
+    /// assert!((bary.weights[0]).abs() < 1e-4);
+    /// assert!((bary.weights[1] - 0.5).abs() < 1e-4);
+    /// ```
+    pub fn world_pos_to_barycentric(&self, pos: Vec2) -> HexBarycentric {
+        let hex = self.world_pos_to_hex(pos);
+        let center = self.hex_to_world_pos(hex);
+        let weights = |dir| {
+            let edge = HexEdge::new(hex, dir);
+            let [mut a, mut b] = self.edge_corners(edge);
+            // Ends are sorted counter clockwise around the containing hexagon
+            if edge.hex() != hex {
+                (a, b) = (b, a);
+            }
+            let (v0, v1, p) = (a - center, b - center, pos - center);
+            let det = v0.perp_dot(v1);
+            let [wa, wb] = [p.perp_dot(v1) / det, v0.perp_dot(p) / det];
+            [1.0 - wa - wb, wa, wb]
+        };
+        // The containing triangle has only positive weights, we keep the closest one to
+        // handle imprecision on the triangle edges
+        let (direction, weights) = Direction::ALL_DIRECTIONS
+            .into_iter()
+            .map(|dir| (dir, weights(dir)))
+            .max_by(|(_, a), (_, b)| {
+                let min = |w: &[f32; 3]| w.iter().copied().fold(f32::MAX, f32::min);
+                min(a).total_cmp(&min(b))
+            })
+            .unwrap_or_default();
+        HexBarycentric {
+            hex,
+            direction,
+            weights,
+        }
+    }
+
+    #[must_use]
+    /// Computes world/pixel coordinates `pos` into fractional hexagonal coordinates, without
+    /// rounding to the containing [`Hex`]
+    pub fn world_pos_to_fract_hex(&self, pos: Vec2) -> FractionalHex {
+        let point = (pos - self.origin) * self.axis_scale() / self.hex_size;
+        self.orientation.inverse(point).into()
     }
 
     #[allow(clippy::cast_precision_loss)]
@@ -65,9 +258,284 @@ impl HexLayout {
         let center = self.hex_to_world_pos(hex);
         Direction::ALL_DIRECTIONS.map(|dir| {
             let angle = dir.angle_pointy() + self.orientation.angle_offset;
-            center + Vec2::new(self.hex_size.x * angle.cos(), self.hex_size.y * angle.sin())
+            let corner = Vec2::new(self.hex_size.x * angle.cos(), self.hex_size.y * angle.sin());
+            center + corner * self.axis_scale()
         })
     }
+
+    #[must_use]
+    /// Computes the world/pixel angle in radians, between `0` and `TAU`, of the given
+    /// `direction`, taking [`Self::hex_size`] and axis inversion into account.
+    ///
+    /// For non inverted layouts with regular hexagons and a flat or rotated (see
+    /// [`HexOrientation::from_angle`]) orientation, this matches [`Direction::angle`].
+    /// For [`HexOrientation::pointy`] it does not: the returned angles are 60 degrees more than
+    /// [`Direction::angle_pointy`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout {
+    ///     orientation: HexOrientation::pointy(),
+    ///     ..Default::default()
+    /// };
+    /// let angle = layout.direction_angle(Direction::Top);
+    /// assert!((angle.to_degrees() - 120.0).abs() < 1e-4);
+    /// let angle = Direction::Top.angle(&layout.orientation);
+    /// assert!((angle.to_degrees() - 60.0).abs() < 1e-4);
+    /// ```
+    pub fn direction_angle(&self, direction: Direction) -> f32 {
+        let v = self.hex_to_world_pos(Hex::neighbor_coord(direction)) - self.origin;
+        (-v.y).atan2(v.x).rem_euclid(TAU)
+    }
+
+    #[must_use]
+    /// Computes the world/pixel position of the corner of `hex` in the `diagonal` direction,
+    /// shared with its neighbors in `diagonal.direction_right()` and `diagonal.direction_left()`
+    pub(crate) fn hex_corner(&self, hex: Hex, diagonal: DiagonalDirection) -> Vec2 {
+        let coords = [
+            hex,
+            hex.neighbor(diagonal.direction_right()),
+            hex.neighbor(diagonal.direction_left()),
+        ];
+        coords
+            .map(|h| self.hex_to_world_pos(h))
+            .into_iter()
+            .sum::<Vec2>()
+            / 3.0
+    }
+
+    #[must_use]
+    /// Computes the world/pixel position of the middle of `edge`
+    pub fn edge_to_world_pos(&self, edge: HexEdge) -> Vec2 {
+        let [a, b] = edge.hexes().map(|h| self.hex_to_world_pos(h));
+        (a + b) / 2.0
+    }
+
+    #[must_use]
+    /// Computes the two world/pixel end positions of `edge`.
+    ///
+    /// The ends are sorted counter clockwise around [`HexEdge::hex`] and match its
+    /// [`Self::hex_corners`].
+    pub fn edge_corners(&self, edge: HexEdge) -> [Vec2; 2] {
+        let (hex, dir) = (edge.hex(), edge.direction());
+        [
+            self.hex_corner(hex, dir.diagonal_right()),
+            self.hex_corner(hex, dir.diagonal_left()),
+        ]
+    }
+
+    #[must_use]
+    /// Computes the world/pixel outward unit normal of the hexagon edges in `direction`.
+    ///
+    /// For irregular hexagons (with a non uniform [`Self::hex_size`]), the normal is
+    /// perpendicular to the edge and not necessarily aligned with the neighbor center.
+    pub fn edge_normal(&self, direction: Direction) -> Vec2 {
+        let edge = HexEdge::new(Hex::ZERO, direction);
+        let [a, b] = self.edge_corners(edge);
+        let normal = (b - a).perp().normalize();
+        let outward = self.edge_to_world_pos(edge) - self.origin;
+        if normal.dot(outward) < 0.0 {
+            -normal
+        } else {
+            normal
+        }
+    }
+
+    #[must_use]
+    /// Computes the world/pixel radius of the inner circle of the hexagons, which is the
+    /// minimum distance between an hexagon center and its edges
+    pub fn inner_radius(&self) -> f32 {
+        Direction::ALL_DIRECTIONS
+            .into_iter()
+            .map(|dir| {
+                let [a, _] = self.edge_corners(HexEdge::new(Hex::ZERO, dir));
+                (a - self.origin).dot(self.edge_normal(dir))
+            })
+            .fold(f32::MAX, f32::min)
+    }
+
+    #[must_use]
+    /// Computes the world/pixel radius of the outer circle of the hexagons, which is the
+    /// maximum distance between an hexagon center and its corners
+    pub fn outer_radius(&self) -> f32 {
+        self.hex_corners(Hex::ZERO)
+            .into_iter()
+            .map(|c| c.distance(self.origin))
+            .fold(0.0, f32::max)
+    }
+
+    #[must_use]
+    /// Computes the world/pixel bounding rectangle `[min, max]` of the cell of `hex`,
+    /// delimited by its [`Self::hex_corners`]
+    pub fn hex_rect(&self, hex: Hex) -> [Vec2; 2] {
+        let corners = self.hex_corners(hex);
+        corners
+            .into_iter()
+            .fold([corners[0]; 2], |[min, max], c| [min.min(c), max.max(c)])
+    }
+
+    #[must_use]
+    /// Computes the world/pixel bounding rectangle `[min, max]` of all the cells of `hexes`,
+    /// useful to clamp a camera to a map.
+    ///
+    /// Returns `None` if `hexes` is empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let [min, max] = layout.hexes_rect(shapes::hexagon(Hex::ZERO, 2)).unwrap();
+    /// assert_eq!(min.x, -4.0);
+    /// assert_eq!(max.x, 4.0);
+    /// ```
+    pub fn hexes_rect(&self, hexes: impl IntoIterator<Item = Hex>) -> Option<[Vec2; 2]> {
+        let [cell_min, cell_max] = self.hex_rect(Hex::ZERO).map(|p| p - self.origin);
+        hexes
+            .into_iter()
+            .map(|hex| self.hex_to_world_pos(hex))
+            .fold(None, |rect, p| {
+                let [min, max] = rect.unwrap_or([p; 2]);
+                Some([min.min(p), max.max(p)])
+            })
+            .map(|[min, max]| [min + cell_min, max + cell_max])
+    }
+
+    #[must_use]
+    /// Computes the world/pixel position of `vertex`, matching one of the
+    /// [`Self::hex_corners`] of its adjacent coordinates
+    pub fn vertex_to_world_pos(&self, vertex: HexVertex) -> Vec2 {
+        self.hex_corner(vertex.hex(), vertex.diagonal_direction())
+    }
+
+    #[must_use]
+    /// Returns `true` if the cell of `hex`, delimited by its [`Self::hex_corners`], overlaps
+    /// the world/pixel rectangle between `min` and `max`.
+    ///
+    /// Cells only touching the rectangle are considered overlapping.
+    pub fn hex_intersects_rect(&self, hex: Hex, min: Vec2, max: Vec2) -> bool {
+        let corners = self.hex_corners(hex);
+        let (cell_min, cell_max) = corners
+            .iter()
+            .fold((corners[0], corners[0]), |(a, b), &c| (a.min(c), b.max(c)));
+        if cell_max.cmplt(min).any() || cell_min.cmpgt(max).any() {
+            return false;
+        }
+        // Separating axis test on the three edge normals of the hexagon
+        let rect = [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)];
+        let project = |points: &[Vec2], axis: Vec2| {
+            points.iter().fold((f32::MAX, f32::MIN), |(a, b), p| {
+                let d = p.dot(axis);
+                (a.min(d), b.max(d))
+            })
+        };
+        (0..3).all(|i| {
+            let axis = (corners[i + 1] - corners[i]).perp();
+            let (cell_a, cell_b) = project(&corners, axis);
+            let (rect_a, rect_b) = project(&rect, axis);
+            cell_b >= rect_a && rect_b >= cell_a
+        })
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    /// Computes all hexagonal coordinates whose cell overlaps the world/pixel rectangle
+    /// between `min` and `max`, see [`Self::hex_intersects_rect`].
+    ///
+    /// This is useful for *viewport culling*: retrieving every cell visible by a camera.
+    /// Coordinates are ordered by row (`y`), then by `x`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let hexes: Vec<Hex> = layout
+    ///     .hexes_in_rect(Vec2::splat(-0.1), Vec2::splat(0.1))
+    ///     .collect();
+    /// assert_eq!(hexes, vec![Hex::ZERO]);
+    /// // The rectangle touches the neighbor cells
+    /// let hexes = layout.hexes_in_rect(Vec2::new(-1.0, -0.5), Vec2::new(1.0, 0.5));
+    /// assert_eq!(hexes.len(), 5);
+    /// ```
+    pub fn hexes_in_rect(&self, min: Vec2, max: Vec2) -> impl ExactSizeIterator<Item = Hex> {
+        let (min, max) = (min.min(max), min.max(max));
+        // Cell centers overlapping the rectangle are in the rectangle extended by the cell size
+        let [cell_min, cell_max] = self.hex_rect(Hex::ZERO).map(|p| p - self.origin);
+        let (ext_min, ext_max) = (min - cell_max, max - cell_min);
+        let step = self.hex_to_world_pos(Hex::X) - self.origin;
+        let ranges: Vec<_> = self
+            .row_range(ext_min, ext_max)
+            .filter_map(|y| {
+                // Solves `ext_min <= start + x * step <= ext_max` on both axis
+                let start = self.hex_to_world_pos(Hex::new(0, y));
+                let (mut lo, mut hi) = (f32::MIN, f32::MAX);
+                for axis in 0..2 {
+                    let (from, to) = (ext_min[axis] - start[axis], ext_max[axis] - start[axis]);
+                    if step[axis].abs() <= f32::EPSILON {
+                        if from > 0.0 || to < 0.0 {
+                            return None;
+                        }
+                    } else {
+                        let (a, b) = (from / step[axis], to / step[axis]);
+                        lo = lo.max(a.min(b));
+                        hi = hi.min(a.max(b));
+                    }
+                }
+                let (mut lo, mut hi) = (lo.floor() as i32, hi.ceil() as i32);
+                // Overlapping cells of a row are contiguous, so we trim the range ends
+                while lo <= hi && !self.hex_intersects_rect(Hex::new(lo, y), min, max) {
+                    lo += 1;
+                }
+                while hi >= lo && !self.hex_intersects_rect(Hex::new(hi, y), min, max) {
+                    hi -= 1;
+                }
+                (lo <= hi).then_some((y, lo, hi))
+            })
+            .collect();
+        let count = ranges
+            .iter()
+            .map(|(_, lo, hi)| (hi - lo) as usize + 1)
+            .sum();
+        ExactSizeHexIterator {
+            iter: ranges
+                .into_iter()
+                .flat_map(|(y, lo, hi)| (lo..=hi).map(move |x| Hex::new(x, y))),
+            count,
+        }
+    }
+
+    #[must_use]
+    /// Computes the outlines of `region` as closed polygon loops in world/pixel coordinates.
+    ///
+    /// Every loop point matches a corner of the region coordinates (see [`Self::hex_corners`]).
+    /// The outer boundaries of the region and the boundaries of its holes are all returned,
+    /// following [`HexRegion::boundary_loops`]. The last point of a loop is not repeated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let region: HexRegion = Hex::ZERO.range(2).collect();
+    /// let outlines = layout.region_outlines(&region);
+    /// assert_eq!(outlines.len(), 1);
+    /// assert_eq!(outlines[0].len(), 30);
+    /// ```
+    pub fn region_outlines(&self, region: &HexRegion) -> Vec<Vec<Vec2>> {
+        region
+            .boundary_loops()
+            .into_iter()
+            .map(|edges| {
+                edges
+                    .into_iter()
+                    .map(|(hex, dir)| self.hex_corner(hex, dir.diagonal_left()))
+                    .collect()
+            })
+            .collect()
+    }
 }
 
 impl Default for HexLayout {
@@ -106,6 +574,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fract_hex() {
+        let layout = HexLayout {
+            orientation: HexOrientation::pointy(),
+            origin: Vec2::new(1.0, -4.0),
+            hex_size: Vec2::new(2.0, 3.0),
+        };
+        for pos in [Vec2::ZERO, Vec2::new(10.3, -2.1), Vec2::new(-7.0, 5.5)] {
+            let fract = layout.world_pos_to_fract_hex(pos);
+            assert_eq!(fract.round(), layout.world_pos_to_hex(pos));
+            assert!(layout.fract_hex_to_world_pos(fract).distance(pos) < 1e-4);
+        }
+    }
+
+    #[test]
+    fn region_outlines() {
+        let region: HexRegion = Hex::new(1, -2).ring(2).collect();
+        for orientation in [HexOrientation::flat(), HexOrientation::pointy()] {
+            let layout = HexLayout {
+                orientation,
+                origin: Vec2::new(3.0, -1.0),
+                hex_size: Vec2::new(2.0, 1.5),
+            };
+            let corners: Vec<_> = region.iter().flat_map(|h| layout.hex_corners(h)).collect();
+            let outlines = layout.region_outlines(&region);
+            assert_eq!(outlines.len(), 2);
+            for point in outlines.into_iter().flatten() {
+                assert!(corners.iter().any(|c| c.distance(point) < 1e-4));
+            }
+        }
+    }
+
+    #[test]
+    fn edge_corners() {
+        for orientation in [HexOrientation::flat(), HexOrientation::pointy()] {
+            let layout = HexLayout {
+                orientation,
+                origin: Vec2::new(-1.0, 2.0),
+                hex_size: Vec2::new(1.0, 3.0),
+            };
+            let hex = Hex::new(-4, 2);
+            let corners = layout.hex_corners(hex);
+            for edge in HexEdge::all_edges(hex) {
+                let [a, b] = layout.edge_corners(edge);
+                assert!(corners.iter().any(|c| c.distance(a) < 1e-4));
+                assert!(corners.iter().any(|c| c.distance(b) < 1e-4));
+                assert!(layout.edge_to_world_pos(edge).distance((a + b) / 2.0) < 1e-4);
+                let [va, vb] = edge.vertices().map(|v| layout.vertex_to_world_pos(v));
+                assert!(va.distance(a) < 1e-4);
+                assert!(vb.distance(b) < 1e-4);
+            }
+        }
+    }
+
     #[test]
     fn pointy_corners() {
         let point = Hex::new(0, 0);
@@ -127,4 +649,180 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn inverted_axis() {
+        let base = HexLayout {
+            hex_size: Vec2::new(2.0, 3.0),
+            origin: Vec2::new(5.0, -1.0),
+            ..Default::default()
+        };
+        // Expected `Direction::TopRight` angles, in degrees
+        let angles = [
+            (false, false, 40.893_39),
+            (true, false, 139.106_6),
+            (false, true, 319.106_6),
+            (true, true, 220.893_4),
+        ];
+        for (invert_x, invert_y, degrees) in angles {
+            let layout = base
+                .clone()
+                .with_inverted_x(invert_x)
+                .with_inverted_y(invert_y);
+            assert_eq!(layout.is_mirrored(), invert_x != invert_y);
+            let scale = layout.axis_scale();
+            for hex in Hex::ZERO.range(3) {
+                let pos = layout.hex_to_world_pos(hex);
+                let base_pos = base.hex_to_world_pos(hex);
+                assert!((pos - layout.origin).distance((base_pos - base.origin) * scale) < 1e-4);
+                assert_eq!(layout.world_pos_to_hex(pos), hex);
+                for (corner, base_corner) in
+                    layout.hex_corners(hex).iter().zip(base.hex_corners(hex))
+                {
+                    let expected = (base_corner - base.origin) * scale + layout.origin;
+                    assert!(corner.distance(expected) < 1e-4);
+                }
+            }
+            let angle = layout.direction_angle(Direction::TopRight);
+            assert!((angle.to_degrees() - degrees).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn hexes_in_rect() {
+        let layouts = [
+            HexLayout::default(),
+            HexLayout {
+                orientation: HexOrientation::pointy(),
+                hex_size: Vec2::new(1.5, 0.7),
+                origin: Vec2::new(0.3, -2.0),
+            },
+            HexLayout {
+                orientation: HexOrientation::from_angle(0.3),
+                ..Default::default()
+            }
+            .with_inverted_y(true),
+        ];
+        let rects = [
+            (Vec2::new(-3.2, -1.1), Vec2::new(4.5, 2.9)),
+            (Vec2::new(0.1, 0.1), Vec2::new(0.2, 0.2)),
+            (Vec2::new(-7.0, 1.0), Vec2::new(-6.0, 8.5)),
+        ];
+        for layout in &layouts {
+            for (min, max) in rects {
+                let iter = layout.hexes_in_rect(min, max);
+                let len = iter.len();
+                let hexes: Vec<_> = iter.collect();
+                assert_eq!(hexes.len(), len);
+                let mut expected: Vec<_> = Hex::ZERO
+                    .range(20)
+                    .filter(|h| layout.hex_intersects_rect(*h, min, max))
+                    .collect();
+                expected.sort_by_key(|h| (h.y, h.x));
+                assert_eq!(hexes, expected);
+                // Every rectangle point is covered
+                for t in [0.0, 0.3, 0.7, 1.0] {
+                    for u in [0.0, 0.5, 1.0] {
+                        let pos = min + (max - min) * Vec2::new(t, u);
+                        assert!(hexes.contains(&layout.world_pos_to_hex(pos)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn edge_geometry() {
+        let regular = HexLayout {
+            hex_size: Vec2::splat(2.0),
+            origin: Vec2::new(1.0, -3.0),
+            ..Default::default()
+        };
+        assert!((regular.outer_radius() - 2.0).abs() < 1e-4);
+        assert!((regular.inner_radius() - 3.0_f32.sqrt()).abs() < 1e-4);
+        let layouts = [
+            regular,
+            HexLayout {
+                orientation: HexOrientation::pointy(),
+                hex_size: Vec2::new(1.5, 0.6),
+                ..Default::default()
+            }
+            .with_inverted_x(true),
+        ];
+        for layout in layouts {
+            let hex = Hex::new(2, -5);
+            let center = layout.hex_to_world_pos(hex);
+            for dir in Direction::ALL_DIRECTIONS {
+                let edge = HexEdge::new(hex, dir);
+                let [a, b] = layout.edge_corners(edge);
+                let mid = layout.edge_to_world_pos(edge);
+                assert!(mid.distance((a + b) / 2.0) < 1e-4);
+                // Both hexagons sharing the edge have its ends as corners
+                for h in [hex, hex.neighbor(dir)] {
+                    let corners = layout.hex_corners(h);
+                    for end in [a, b] {
+                        assert!(corners.iter().any(|c| c.distance(end) < 1e-4));
+                    }
+                }
+                let normal = layout.edge_normal(dir);
+                assert!((normal.length() - 1.0).abs() < 1e-4);
+                assert!(normal.dot(b - a).abs() < 1e-4);
+                assert!(normal.dot(mid - center) > 0.0);
+                assert!((normal + layout.edge_normal(-dir)).length() < 1e-4);
+                let distance = (a - center).dot(normal);
+                assert!(distance >= layout.inner_radius() - 1e-4);
+            }
+            let [min, max] = layout.hex_rect(hex);
+            for corner in layout.hex_corners(hex) {
+                assert!(corner.distance(center) <= layout.outer_radius() + 1e-4);
+                assert!(corner.cmpge(min - 1e-4).all() && corner.cmple(max + 1e-4).all());
+            }
+            let hexes = || Hex::ZERO.range(3);
+            let [min, max] = layout.hexes_rect(hexes()).unwrap();
+            let expected = hexes()
+                .map(|h| layout.hex_rect(h))
+                .reduce(|[a, b], [c, d]| [a.min(c), b.max(d)])
+                .unwrap();
+            assert!(min.distance(expected[0]) < 1e-4);
+            assert!(max.distance(expected[1]) < 1e-4);
+            assert!(layout.hexes_rect([]).is_none());
+        }
+    }
+
+    #[test]
+    fn snapping() {
+        let layouts = [
+            HexLayout::default(),
+            HexLayout {
+                orientation: HexOrientation::pointy(),
+                hex_size: Vec2::new(2.0, 2.0),
+                origin: Vec2::new(3.0, 1.0),
+            }
+            .with_inverted_y(true),
+        ];
+        for layout in layouts {
+            for hex in Hex::ZERO.range(2) {
+                for dir in Direction::ALL_DIRECTIONS {
+                    let edge = HexEdge::new(hex, dir);
+                    let vertex = HexVertex::new(hex, dir);
+                    let center = layout.hex_to_world_pos(hex);
+                    // Slightly inside the hexagon
+                    let mid = layout.edge_to_world_pos(edge).lerp(center, 0.1);
+                    assert_eq!(layout.world_pos_to_edge(mid), edge);
+                    let corner = layout.vertex_to_world_pos(vertex).lerp(center, 0.1);
+                    assert_eq!(layout.world_pos_to_vertex(corner), vertex);
+                    let [mut a, mut b] = layout.edge_corners(edge);
+                    if edge.hex() != hex {
+                        (a, b) = (b, a);
+                    }
+                    let pos = center * 0.2 + a * 0.5 + b * 0.3;
+                    let bary = layout.world_pos_to_barycentric(pos);
+                    assert_eq!((bary.hex, bary.direction), (hex, dir));
+                    for (w, expected) in bary.weights.into_iter().zip([0.2, 0.5, 0.3]) {
+                        assert!((w - expected).abs() < 1e-4);
+                    }
+                }
+            }
+        }
+    }
 }
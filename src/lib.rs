@@ -122,7 +122,7 @@
 //! The [`MeshInfo`] type provides the following mesh generations:
 //! - [`MeshInfo::hexagonal_plane`] (7 vertices) useful for 2D games
 //! - [`MeshInfo::cheap_hexagonal_column`] (13 vertices) with merged vertices and useful only for
-//! unlit games
+//!   unlit games
 //! - [`MeshInfo::partial_hexagonal_column`] (31 vertices) without the bottom face
 //! - [`MeshInfo::hexagonal_column`] (38 vertices) with the bottom face
 #![forbid(unsafe_code)]
@@ -131,12 +131,16 @@
 /// Non exhaustive collection of classic algorithms.
 #[cfg(feature = "algorithms")]
 pub mod algorithms;
+/// ASCII art rendering and parsing of hexagonal grids
+pub mod ascii;
 /// Hexagonal range bounds module
 pub mod bounds;
 /// Hexagonal coordinates conversion module
 pub mod conversions;
 /// Hexagonal directions module
 pub mod direction;
+/// Hexagonal edge coordinates module
+pub mod edge;
 /// Hexagonal coordinates module
 pub mod hex;
 /// Wraparound hex grid module
@@ -145,12 +149,27 @@ pub mod hex_map;
 pub mod layout;
 /// Mesh generation utils module
 pub mod mesh;
+/// Hexagonal Z-order (Morton) spatial encoding module
+pub mod morton;
+/// Text notation parsing and formatting module
+pub mod notation;
 /// Hexagon oritentation module
 pub mod orientation;
+/// World space shapes rasterization module
+pub mod raster;
+/// Continuous ray traversal module
+pub mod raycast;
+/// Hexagonal region module
+pub mod region;
 /// Map shapes generation functions
 pub mod shapes;
+/// SVG export of hexagonal grids
+pub mod svg;
+/// Hexagonal vertex coordinates module
+pub mod vertex;
 
 pub use glam::{IVec2, IVec3, Vec2};
 pub use {
-    bounds::*, conversions::*, direction::*, hex::*, hex_map::*, layout::*, mesh::*, orientation::*,
+    bounds::*, conversions::*, direction::*, edge::*, hex::*, hex_map::*, layout::*, mesh::*,
+    morton::*, notation::*, orientation::*, raster::*, raycast::*, region::*, vertex::*,
 };
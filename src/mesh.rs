@@ -4,6 +4,17 @@ use glam::{Quat, Vec2, Vec3};
 const UP_VECTOR: [f32; 3] = [0.0, 1.0, 0.0];
 const DOWN_VECTOR: [f32; 3] = [0.0, -1.0, 0.0];
 
+/// Reverses the winding of every triangle in `indices` if `layout` is mirrored, since mirrored
+/// layouts also reverse the rotational order of [`HexLayout::hex_corners`]
+fn layout_winding(layout: &HexLayout, mut indices: Vec<u16>) -> Vec<u16> {
+    if layout.is_mirrored() {
+        for triangle in indices.chunks_exact_mut(3) {
+            triangle.swap(0, 2);
+        }
+    }
+    indices
+}
+
 #[derive(Debug, Clone)]
 /// Mesh information. The `const LEN` attribute ensures that there is the same number of vertices, normals and uvs
 pub struct MeshInfo<const LEN: usize> {
@@ -74,14 +85,17 @@ impl MeshInfo<7> {
                 (corners[5] + uv_delta).to_array(),
             ],
             normals: [UP_VECTOR; 7],
-            indices: vec![
-                1, 0, 2, // 1
-                2, 0, 3, // 2
-                3, 0, 4, // 3
-                4, 0, 5, // 4
-                5, 0, 6, // 5
-                6, 0, 1, // 6
-            ],
+            indices: layout_winding(
+                layout,
+                vec![
+                    1, 0, 2, // 1
+                    2, 0, 3, // 2
+                    3, 0, 4, // 3
+                    4, 0, 5, // 4
+                    5, 0, 6, // 5
+                    6, 0, 1, // 6
+                ],
+            ),
             facing: UP_VECTOR,
         }
     }
@@ -245,7 +259,7 @@ impl MeshInfo<31> {
                 quad_normals[5],
                 quad_normals[5],
             ],
-            indices,
+            indices: layout_winding(layout, indices),
             facing: UP_VECTOR,
         }
     }
@@ -328,7 +342,7 @@ impl MeshInfo<13> {
                 quad_normals[5],
             ],
             uvs: [[0., 1.]; 13], // TODO: Find decent UV mapping
-            indices,
+            indices: layout_winding(layout, indices),
             facing: UP_VECTOR,
         }
     }
@@ -525,8 +539,47 @@ impl MeshInfo<38> {
                 DOWN_VECTOR,
                 DOWN_VECTOR,
             ],
-            indices,
+            indices: layout_winding(layout, indices),
             facing: UP_VECTOR,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that every triangle of `mesh` faces away from `center`
+    fn assert_outward<const LEN: usize>(mesh: &MeshInfo<LEN>, center: Vec3) {
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] =
+                [0, 1, 2].map(|i| Vec3::from_array(mesh.vertices[triangle[i] as usize]));
+            let normal = (b - a).cross(c - a);
+            assert!(normal.dot((a + b + c) / 3.0 - center) > 0.0, "{triangle:?}");
+        }
+    }
+
+    #[test]
+    fn layout_winding() {
+        let hex = Hex::new(2, -1);
+        let height = 3.0;
+        for (invert_x, invert_y) in [(false, false), (true, false), (false, true), (true, true)] {
+            let layout = HexLayout::default()
+                .with_inverted_x(invert_x)
+                .with_inverted_y(invert_y);
+            let center = layout.hex_to_world_pos(hex);
+            let below = Vec3::new(center.x, -1.0, center.y);
+            assert_outward(&MeshInfo::hexagonal_plane(&layout, hex), below);
+            let center = Vec3::new(center.x, height / 2.0, center.y);
+            assert_outward(
+                &MeshInfo::partial_hexagonal_column(&layout, hex, height),
+                center,
+            );
+            assert_outward(
+                &MeshInfo::cheap_hexagonal_column(&layout, hex, height),
+                center,
+            );
+            assert_outward(&MeshInfo::hexagonal_column(&layout, hex, height), center);
+        }
+    }
+}
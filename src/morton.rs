@@ -0,0 +1,325 @@
+use crate::Hex;
+use std::ops::RangeInclusive;
+
+/// Spreads the bits of `v` to the even bits of a `u64`
+const fn spread(v: u32) -> u64 {
+    let mut v = v as u64;
+    v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    v = (v | (v << 1)) & 0x5555_5555_5555_5555;
+    v
+}
+
+/// Compacts the even bits of `v` into a `u32`, reversing [`spread`]
+#[allow(clippy::cast_possible_truncation)]
+const fn compact(v: u64) -> u32 {
+    let mut v = v & 0x5555_5555_5555_5555;
+    v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+    v = (v | (v >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v >> 4)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v >> 8)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v >> 16)) & 0x0000_0000_FFFF_FFFF;
+    v as u32
+}
+
+/// Z-order (Morton) spatial encoding of hexagonal coordinates.
+///
+/// The axial coordinates are biased to be unsigned and their bits are interleaved, giving a
+/// single `u64` key per [`Hex`]. Sorting coordinates by key keeps nearby coordinates close
+/// together, which is useful for cache friendly storage and database range queries.
+///
+/// A code has a `level`: a level `0` code identifies a single [`Hex`], a level `n` code
+/// identifies a block of `2^n` by `2^n` coordinates (a parallelogram in axial space) sharing
+/// the same key prefix. Going up and down in levels is done by truncating or appending key
+/// digits, see [`Self::parent`] and [`Self::children`].
+///
+/// Codes are ordered in Z-order, by the first coordinate of their block. Blocks starting at
+/// the same coordinate are ordered from the highest to the lowest level, so a block comes
+/// right before its descendants.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let hex = hex(-5, 12);
+/// let code = MortonCode::from(hex);
+/// assert_eq!(code.to_hex(), hex);
+/// // The parent block contains `hex` and 3 other coordinates
+/// let parent = code.parent().unwrap();
+/// assert!(parent.contains(hex));
+/// assert!(parent.children().unwrap().contains(&code));
+/// // Sort coordinates in Z-order
+/// let mut coords: Vec<Hex> = Hex::ZERO.range(10).collect();
+/// coords.sort_by_key(|h| MortonCode::from(*h));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+pub struct MortonCode {
+    /// The code level, coordinates in the block share `2 * (32 - level)` key bits
+    level: u32,
+    /// The block key, without the truncated digits
+    code: u64,
+}
+
+impl MortonCode {
+    /// Maximum code level, matching a single block containing all coordinates
+    pub const MAX_LEVEL: u32 = 32;
+
+    #[inline]
+    #[must_use]
+    /// Encodes `hex` into a level 0 code
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn from_hex(hex: Hex) -> Self {
+        // Biasing by flipping the sign bit keeps the coordinates order
+        let [x, y] = [hex.x as u32, hex.y as u32];
+        let [x, y] = [x ^ 0x8000_0000, y ^ 0x8000_0000];
+        Self {
+            level: 0,
+            code: spread(x) | (spread(y) << 1),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Instantiates a code from its raw `code` value and `level`.
+    ///
+    /// Returns `None` if `level` is above [`Self::MAX_LEVEL`] or if `code` has more than
+    /// `2 * (32 - level)` significant bits
+    pub const fn from_raw(code: u64, level: u32) -> Option<Self> {
+        if level > Self::MAX_LEVEL || (level > 0 && code >> (64 - 2 * level) != 0) {
+            return None;
+        }
+        Some(Self { level, code })
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the raw code value. Level 0 codes can be used as `u64` keys
+    pub const fn code(self) -> u64 {
+        self.code
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the code level
+    pub const fn level(self) -> u32 {
+        self.level
+    }
+
+    #[inline]
+    #[must_use]
+    /// Decodes the code into the coordinate with the smallest `x` and `y` of its block.
+    ///
+    /// For level 0 codes this is the encoded [`Hex`]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn to_hex(self) -> Hex {
+        let code = self.first_code();
+        let [x, y] = [
+            compact(code) ^ 0x8000_0000,
+            compact(code >> 1) ^ 0x8000_0000,
+        ];
+        Hex::new(x as i32, y as i32)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the parent code, containing `self` and 3 siblings, by truncating the last key
+    /// digit.
+    ///
+    /// Returns `None` if `self` is at [`Self::MAX_LEVEL`]
+    pub const fn parent(self) -> Option<Self> {
+        if self.level >= Self::MAX_LEVEL {
+            return None;
+        }
+        Some(Self {
+            level: self.level + 1,
+            code: self.code >> 2,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the ancestor code `levels` above `self`, or `None` if it is above
+    /// [`Self::MAX_LEVEL`]
+    pub const fn ancestor(self, levels: u32) -> Option<Self> {
+        let level = self.level.saturating_add(levels);
+        if level > Self::MAX_LEVEL {
+            return None;
+        }
+        Some(Self {
+            level,
+            // Shifting a `u64` by 64 bits is not allowed
+            code: if levels >= 32 {
+                0
+            } else {
+                self.code >> (2 * levels)
+            },
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the 4 children codes of `self` by appending a key digit, in Z-order.
+    ///
+    /// Returns `None` for level 0 codes
+    pub const fn children(self) -> Option<[Self; 4]> {
+        if self.level == 0 {
+            return None;
+        }
+        let (level, code) = (self.level - 1, self.code << 2);
+        Some([
+            Self { level, code },
+            Self {
+                level,
+                code: code | 1,
+            },
+            Self {
+                level,
+                code: code | 2,
+            },
+            Self {
+                level,
+                code: code | 3,
+            },
+        ])
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the level 0 code of the first coordinate of the block
+    const fn first_code(self) -> u64 {
+        if self.level >= 32 {
+            0
+        } else {
+            self.code << (2 * self.level)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the range of level 0 codes contained in the block, useful for range queries
+    pub const fn code_range(self) -> RangeInclusive<u64> {
+        let first = self.first_code();
+        let len = if self.level >= 32 {
+            u64::MAX
+        } else {
+            (1 << (2 * self.level)) - 1
+        };
+        first..=first + len
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checks if `hex` is in the block
+    pub const fn contains(self, hex: Hex) -> bool {
+        let code = Self::from_hex(hex).code;
+        let range = self.code_range();
+        code >= *range.start() && code <= *range.end()
+    }
+}
+
+impl PartialOrd for MortonCode {
+    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for MortonCode {
+    fn cmp(&self, rhs: &Self) -> std::cmp::Ordering {
+        self.first_code()
+            .cmp(&rhs.first_code())
+            .then(rhs.level.cmp(&self.level))
+    }
+}
+
+impl From<Hex> for MortonCode {
+    #[inline]
+    fn from(hex: Hex) -> Self {
+        Self::from_hex(hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode() {
+        for hex in Hex::new(3, -7).range(20).chain([
+            Hex::new(i32::MAX, i32::MIN),
+            Hex::new(i32::MIN, i32::MAX),
+            Hex::ZERO,
+        ]) {
+            let code = MortonCode::from(hex);
+            assert_eq!(code.level(), 0);
+            assert_eq!(code.to_hex(), hex);
+            assert_eq!(MortonCode::from_raw(code.code(), 0), Some(code));
+        }
+    }
+
+    #[test]
+    fn hierarchy() {
+        let hex = Hex::new(-13, 6);
+        let code = MortonCode::from(hex);
+        assert!(code.children().is_none());
+        let mut current = code;
+        for level in 1..=MortonCode::MAX_LEVEL {
+            let parent = current.parent().unwrap();
+            assert_eq!(parent.level(), level);
+            assert!(parent.contains(hex));
+            assert!(parent.children().unwrap().contains(&current));
+            assert_eq!(code.ancestor(level), Some(parent));
+            current = parent;
+        }
+        assert!(current.parent().is_none());
+        assert_eq!(current.code_range(), 0..=u64::MAX);
+        // Level 1 blocks are 2 by 2 parallelograms
+        let parent = MortonCode::from(Hex::new(4, 8)).parent().unwrap();
+        let block: Vec<_> = parent
+            .children()
+            .unwrap()
+            .into_iter()
+            .map(MortonCode::to_hex)
+            .collect();
+        assert_eq!(
+            block,
+            vec![
+                Hex::new(4, 8),
+                Hex::new(5, 8),
+                Hex::new(4, 9),
+                Hex::new(5, 9)
+            ]
+        );
+        assert_eq!(MortonCode::from_raw(u64::MAX, 1), None);
+        assert_eq!(MortonCode::from_raw(0, 33), None);
+    }
+
+    #[test]
+    fn locality() {
+        let mut coords: Vec<Hex> = Hex::ZERO.range(30).collect();
+        coords.sort_by_key(|h| MortonCode::from(*h));
+        let average_jump = coords
+            .windows(2)
+            .map(|w| u64::from(w[0].unsigned_distance_to(w[1])))
+            .sum::<u64>()
+            / coords.len() as u64;
+        assert!(average_jump <= 2);
+    }
+
+    #[test]
+    fn ordering() {
+        let code = MortonCode::from(Hex::new(-13, 6));
+        let parent = code.parent().unwrap();
+        let children = parent.children().unwrap();
+        let next = MortonCode::from_raw(parent.code() + 1, parent.level()).unwrap();
+        assert!(parent < children[0]);
+        assert!(children.windows(2).all(|w| w[0] < w[1]));
+        assert!(children[3] < next);
+        // Level 0 codes follow their raw code order
+        let mut codes: Vec<_> = Hex::ZERO.range(5).map(MortonCode::from).collect();
+        codes.sort();
+        assert!(codes.windows(2).all(|w| w[0].code() < w[1].code()));
+    }
+}
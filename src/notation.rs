@@ -0,0 +1,303 @@
+use crate::{DiagonalDirection, Direction, Hex, OffsetHexMode};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+/// Error returned when parsing [`Hex`] coordinates from text fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseHexError {
+    /// The text doesn't follow the expected format
+    InvalidFormat,
+    /// A coordinate is not a valid integer
+    InvalidCoordinate(ParseIntError),
+    /// The board notation column letters are invalid or out of range
+    InvalidColumn,
+    /// The board notation row number is invalid or out of range
+    InvalidRow,
+}
+
+impl Display for ParseHexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "invalid hex format, expected `(x, y)`"),
+            Self::InvalidCoordinate(e) => write!(f, "invalid hex coordinate: {e}"),
+            Self::InvalidColumn => write!(f, "invalid board notation column"),
+            Self::InvalidRow => write!(f, "invalid board notation row"),
+        }
+    }
+}
+
+impl Error for ParseHexError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidCoordinate(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseHexError {
+    fn from(e: ParseIntError) -> Self {
+        Self::InvalidCoordinate(e)
+    }
+}
+
+/// Error returned when parsing a [`Direction`] or [`DiagonalDirection`] from text fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError;
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid direction name")
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// Formats [`Hex`] coordinates with the axial notation `(x, y)`
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// assert_eq!(hex(3, -2).to_string(), "(3, -2)");
+/// ```
+impl Display for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Parses [`Hex`] coordinates from the axial notation `(x, y)`.
+///
+/// Whitespace around the coordinates is ignored and the parentheses are optional.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// assert_eq!("(3, -2)".parse(), Ok(hex(3, -2)));
+/// assert_eq!("3,-2".parse(), Ok(hex(3, -2)));
+/// assert!("(3; -2)".parse::<Hex>().is_err());
+/// ```
+impl FromStr for Hex {
+    type Err = ParseHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = match s.strip_prefix('(') {
+            Some(inner) => inner
+                .strip_suffix(')')
+                .ok_or(ParseHexError::InvalidFormat)?,
+            None => s,
+        };
+        let (x, y) = s.split_once(',').ok_or(ParseHexError::InvalidFormat)?;
+        Ok(Self::new(x.trim().parse()?, y.trim().parse()?))
+    }
+}
+
+impl Hex {
+    /// Formats `self` with a tabletop style *board notation*: a column letter followed by a
+    /// row number, like `B3`.
+    ///
+    /// The [offset](OffsetHexMode) coordinates of `self` are used, the `[0, 0]` coordinate
+    /// being `A1`. Columns after `Z` follow spreadsheet style: `AA`, `AB`, etc.
+    ///
+    /// Returns `None` if the offset coordinates are negative or equal to `i32::MAX`, as they can't
+    /// be represented
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let mode = OffsetHexMode::OddColumns;
+    /// let coord = Hex::from_offset_coordinates([1, 2], mode);
+    /// assert_eq!(coord.to_board_notation(mode).as_deref(), Some("B3"));
+    /// assert_eq!(Hex::from_board_notation("B3", mode), Ok(coord));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn to_board_notation(self, mode: OffsetHexMode) -> Option<String> {
+        let [col, row] = self.to_offset_coordinates(mode);
+        if col < 0 || row < 0 {
+            return None;
+        }
+        // One-based numbering must fit in `i32` to be parsed back
+        let [col, row] = [col.checked_add(1)?, row.checked_add(1)?];
+        let mut letters = Vec::new();
+        let mut col = col as u32;
+        while col > 0 {
+            col -= 1;
+            letters.push(char::from(b'A' + (col % 26) as u8));
+            col /= 26;
+        }
+        let column: String = letters.into_iter().rev().collect();
+        Some(format!("{column}{row}"))
+    }
+
+    /// Parses a tabletop style *board notation* (like `B3`) into coordinates, following the
+    /// given offset `mode`.
+    ///
+    /// Column letters are case insensitive. See [`Self::to_board_notation`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the column letters or the row number are missing, invalid or out
+    /// of range
+    pub fn from_board_notation(s: &str, mode: OffsetHexMode) -> Result<Self, ParseHexError> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(s.len());
+        let (column, row) = s.split_at(split);
+        if column.is_empty() {
+            return Err(ParseHexError::InvalidColumn);
+        }
+        let col = column.bytes().try_fold(0_i32, |acc, c| {
+            let digit = i32::from(c.to_ascii_uppercase() - b'A') + 1;
+            acc.checked_mul(26)?.checked_add(digit)
+        });
+        let col = col.ok_or(ParseHexError::InvalidColumn)? - 1;
+        if row.is_empty() || !row.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(ParseHexError::InvalidRow);
+        }
+        let row: i32 = row.parse().map_err(|_| ParseHexError::InvalidRow)?;
+        if row == 0 {
+            return Err(ParseHexError::InvalidRow);
+        }
+        Ok(Self::from_offset_coordinates([col, row - 1], mode))
+    }
+}
+
+/// Normalizes a direction name, ignoring case, `_`, `-` and whitespace
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| !matches!(c, '_' | '-') && !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Formats a [`Direction`] with its name, like `TopRight`
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Parses a [`Direction`] from its name.
+///
+/// Names are case insensitive, `_`, `-` and whitespace are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// assert_eq!("TopRight".parse(), Ok(Direction::TopRight));
+/// assert_eq!("bottom_left".parse(), Ok(Direction::BottomLeft));
+/// assert_eq!(Direction::Top.to_string(), "Top");
+/// ```
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = normalize(s);
+        Self::iter()
+            .find(|dir| normalize(&dir.to_string()) == name)
+            .ok_or(ParseDirectionError)
+    }
+}
+
+/// Formats a [`DiagonalDirection`] with its name, like `TopRight`
+impl Display for DiagonalDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Parses a [`DiagonalDirection`] from its name.
+///
+/// Names are case insensitive, `_`, `-` and whitespace are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// assert_eq!("right".parse(), Ok(DiagonalDirection::Right));
+/// assert!("bottom".parse::<DiagonalDirection>().is_err());
+/// ```
+impl FromStr for DiagonalDirection {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = normalize(s);
+        Self::iter()
+            .find(|dir| normalize(&dir.to_string()) == name)
+            .ok_or(ParseDirectionError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_round_trip() {
+        for hex in Hex::new(-3, 8).range(10) {
+            assert_eq!(hex.to_string().parse(), Ok(hex));
+        }
+        assert_eq!(" ( -1 ,2 ) ".parse(), Ok(Hex::new(-1, 2)));
+        assert_eq!("(1, 2".parse::<Hex>(), Err(ParseHexError::InvalidFormat));
+        assert_eq!("1 2".parse::<Hex>(), Err(ParseHexError::InvalidFormat));
+        assert!(matches!(
+            "(a, 2)".parse::<Hex>(),
+            Err(ParseHexError::InvalidCoordinate(_))
+        ));
+    }
+
+    #[test]
+    fn board_notation() {
+        let mode = OffsetHexMode::EvenRows;
+        for col in [0, 1, 25, 26, 27, 701, 702] {
+            for row in [0, 1, 99] {
+                let hex = Hex::from_offset_coordinates([col, row], mode);
+                let notation = hex.to_board_notation(mode).unwrap();
+                assert_eq!(Hex::from_board_notation(&notation, mode), Ok(hex));
+            }
+        }
+        let hex = |col, row| Hex::from_offset_coordinates([col, row], mode);
+        assert_eq!(hex(0, 0).to_board_notation(mode).as_deref(), Some("A1"));
+        assert_eq!(hex(26, 9).to_board_notation(mode).as_deref(), Some("AA10"));
+        assert_eq!(hex(701, 0).to_board_notation(mode).as_deref(), Some("ZZ1"));
+        assert_eq!(hex(-1, 0).to_board_notation(mode), None);
+        let odd = OffsetHexMode::OddRows;
+        let hex_max_row = Hex::from_offset_coordinates([0, i32::MAX], odd);
+        assert_eq!(hex_max_row.to_board_notation(odd), None);
+        assert_eq!(hex(i32::MAX, 0).to_board_notation(mode), None);
+        let last = hex(i32::MAX - 1, i32::MAX - 1);
+        let notation = last.to_board_notation(mode).unwrap();
+        assert_eq!(Hex::from_board_notation(&notation, mode), Ok(last));
+        assert_eq!(Hex::from_board_notation("aa10", mode), Ok(hex(26, 9)));
+        let err = |s| Hex::from_board_notation(s, mode).unwrap_err();
+        assert_eq!(err("12"), ParseHexError::InvalidColumn);
+        assert_eq!(err("A"), ParseHexError::InvalidRow);
+        assert_eq!(err("A0"), ParseHexError::InvalidRow);
+        assert_eq!(err("A-3"), ParseHexError::InvalidRow);
+        assert_eq!(err("ZZZZZZZZZ1"), ParseHexError::InvalidColumn);
+    }
+
+    #[test]
+    fn directions() {
+        for dir in Direction::iter() {
+            assert_eq!(dir.to_string().parse(), Ok(dir));
+            assert_eq!(dir.to_string().to_uppercase().parse(), Ok(dir));
+        }
+        for dir in DiagonalDirection::iter() {
+            assert_eq!(dir.to_string().parse(), Ok(dir));
+        }
+        assert_eq!("top left".parse(), Ok(Direction::TopLeft));
+        assert_eq!("up".parse::<Direction>(), Err(ParseDirectionError));
+    }
+}
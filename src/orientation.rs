@@ -1,5 +1,6 @@
 use crate::direction::angles::DIRECTION_ANGLE_OFFSET;
 use crate::Direction;
+use glam::Vec2;
 
 const SQRT_3: f32 = 1.732_050_8;
 
@@ -8,6 +9,8 @@ static POINTY_ORIENTATION: HexOrientation = HexOrientation {
     forward_matrix: [SQRT_3, SQRT_3 / 2.0, 0.0, 3.0 / 2.0],
     inverse_matrix: [SQRT_3 / 3.0, -1.0 / 3.0, 0.0, 2.0 / 3.0],
     angle_offset: DIRECTION_ANGLE_OFFSET, // 30 degrees
+    invert_x: false,
+    invert_y: false,
 };
 
 // TODO: make const
@@ -15,9 +18,14 @@ static FLAT_ORIENTATION: HexOrientation = HexOrientation {
     forward_matrix: [3.0 / 2.0, 0.0, SQRT_3 / 2.0, SQRT_3],
     inverse_matrix: [2.0 / 3.0, 0.0, -1.0 / 3.0, SQRT_3 / 3.0],
     angle_offset: 0.0, // 0 degrees
+    invert_x: false,
+    invert_y: false,
 };
 
-/// Hexagonal orientation, either `pointy` or `flat`
+/// Hexagonal orientation, usually `pointy` or `flat`, or any rotation of them.
+///
+/// The orientation also holds the world/pixel axis inversion of a [`crate::HexLayout`], see
+/// [`crate::HexLayout::with_inverted_x`]
 ///
 /// # Usage
 ///
@@ -25,6 +33,8 @@ static FLAT_ORIENTATION: HexOrientation = HexOrientation {
 /// # use hexx::*;
 /// let flat = HexOrientation::flat();
 /// let pointy = HexOrientation::pointy();
+/// // Flat hexagons rotated by 15 degrees
+/// let rotated = HexOrientation::from_angle(15_f32.to_radians());
 /// ```
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
@@ -33,8 +43,14 @@ pub struct HexOrientation {
     pub(crate) forward_matrix: [f32; 4],
     /// Matrix used to compute world/pixel coordinates to hexagonal coordinates
     pub(crate) inverse_matrix: [f32; 4],
-    /// orientation offset in radians
+    /// Counter clockwise rotation in radians of the hexagon corners, from the flat orientation
     pub(crate) angle_offset: f32,
+    /// World/pixel `x` axis inversion, see [`crate::HexLayout::with_inverted_x`]
+    #[cfg_attr(feature = "ser_de", serde(default))]
+    pub(crate) invert_x: bool,
+    /// World/pixel `y` axis inversion, see [`crate::HexLayout::with_inverted_y`]
+    #[cfg_attr(feature = "ser_de", serde(default))]
+    pub(crate) invert_y: bool,
 }
 
 impl HexOrientation {
@@ -52,12 +68,80 @@ impl HexOrientation {
         FLAT_ORIENTATION
     }
 
+    #[must_use]
+    /// Flat top hexagonal orientation rotated counter clockwise by `angle` radians.
+    ///
+    /// An `angle` of 0 is equivalent to [`Self::flat`]. An `angle` of 30 degrees gives the same
+    /// hexagon corners and [`Direction::angle`] values as [`Self::pointy`], but *not* the same
+    /// axes: [`crate::Hex::X`] is placed 60 degrees away from its [`Self::pointy`] position.
+    /// Unlike for [`Self::pointy`] (see [`Direction::angle`]), direction angles of rotated
+    /// orientations match their world/pixel directions.
+    /// As [`Direction::angle`] values are measured clockwise, they are decreased by `angle`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let orientation = HexOrientation::from_angle(15_f32.to_radians());
+    /// let angle = orientation.direction_angle(Direction::Top);
+    /// assert!((angle.to_degrees() - 75.0).abs() < 1e-4);
+    /// ```
+    pub fn from_angle(angle: f32) -> Self {
+        let flat = FLAT_ORIENTATION;
+        let (sin, cos) = angle.sin_cos();
+        let [a, b, c, d] = flat.forward_matrix;
+        let forward_matrix = [
+            cos.mul_add(a, -sin * c),
+            cos.mul_add(b, -sin * d),
+            sin.mul_add(a, cos * c),
+            sin.mul_add(b, cos * d),
+        ];
+        let [a, b, c, d] = flat.inverse_matrix;
+        let inverse_matrix = [
+            a.mul_add(cos, -b * sin),
+            a.mul_add(sin, b * cos),
+            c.mul_add(cos, -d * sin),
+            c.mul_add(sin, d * cos),
+        ];
+        Self {
+            forward_matrix,
+            inverse_matrix,
+            angle_offset: angle,
+            invert_x: false,
+            invert_y: false,
+        }
+    }
+
     #[must_use]
     #[inline]
     /// Computes the angle in radians of the given `direction` in the current orientation
     pub fn direction_angle(&self, direction: Direction) -> f32 {
         direction.angle(self)
     }
+
+    #[must_use]
+    #[inline]
+    /// Applies the forward matrix to `point`, converting hexagonal coordinates into unit
+    /// world/pixel coordinates
+    pub(crate) fn forward(&self, point: Vec2) -> Vec2 {
+        let matrix = self.forward_matrix;
+        Vec2::new(
+            matrix[0].mul_add(point.x, matrix[1] * point.y),
+            matrix[2].mul_add(point.x, matrix[3] * point.y),
+        )
+    }
+
+    #[must_use]
+    #[inline]
+    /// Applies the inverse matrix to `point`, converting unit world/pixel coordinates into
+    /// hexagonal coordinates
+    pub(crate) fn inverse(&self, point: Vec2) -> Vec2 {
+        let matrix = self.inverse_matrix;
+        Vec2::new(
+            matrix[0].mul_add(point.x, matrix[1] * point.y),
+            matrix[2].mul_add(point.x, matrix[3] * point.y),
+        )
+    }
 }
 
 impl Default for HexOrientation {
@@ -65,3 +149,94 @@ impl Default for HexOrientation {
         Self::flat()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hex, HexLayout};
+    use std::f32::consts::{PI, TAU};
+
+    fn assert_matrix_eq(a: [f32; 4], b: [f32; 4]) {
+        for (a, b) in a.into_iter().zip(b) {
+            assert!((a - b).abs() < 1e-5, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn from_angle() {
+        let flat = HexOrientation::from_angle(0.0);
+        assert_matrix_eq(flat.forward_matrix, FLAT_ORIENTATION.forward_matrix);
+        assert_matrix_eq(flat.inverse_matrix, FLAT_ORIENTATION.inverse_matrix);
+        // Same hexagons and direction angles as pointy, with different axial axes
+        let rotated = HexOrientation::from_angle(PI / 6.0);
+        assert!((rotated.angle_offset - POINTY_ORIENTATION.angle_offset).abs() < 1e-6);
+        let [pointy, rotated] = [POINTY_ORIENTATION, rotated].map(|orientation| HexLayout {
+            orientation,
+            ..Default::default()
+        });
+        for (a, b) in pointy
+            .hex_corners(Hex::ZERO)
+            .into_iter()
+            .zip(rotated.hex_corners(Hex::ZERO))
+        {
+            assert!(a.distance(b) < 1e-5);
+        }
+        for hex in Hex::ZERO.range(3) {
+            let pos = rotated.hex_to_world_pos(hex);
+            assert!(
+                pointy
+                    .hex_to_world_pos(pointy.world_pos_to_hex(pos))
+                    .distance(pos)
+                    < 1e-5
+            );
+        }
+        for dir in Direction::iter() {
+            let [a, b] = [&pointy, &rotated].map(|l| l.orientation.direction_angle(dir));
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn rotated_layout() {
+        for degrees in [15.0_f32, -42.0, 90.0, 200.0] {
+            let angle = degrees.to_radians();
+            let layout = HexLayout {
+                orientation: HexOrientation::from_angle(angle),
+                origin: Vec2::new(3.0, -2.0),
+                hex_size: Vec2::new(2.0, 2.0),
+            };
+            for hex in Hex::new(1, -3).range(4) {
+                let pos = layout.hex_to_world_pos(hex);
+                assert_eq!(layout.world_pos_to_hex(pos), hex);
+                // Corners are at the hexagon size distance
+                for corner in layout.hex_corners(hex) {
+                    assert!((corner.distance(pos) - 2.0).abs() < 1e-4);
+                }
+                // Every corner is shared with two neighbors
+                for corner in layout.hex_corners(hex) {
+                    let count = hex
+                        .all_neighbors()
+                        .into_iter()
+                        .filter(|h| {
+                            layout
+                                .hex_corners(*h)
+                                .iter()
+                                .any(|c| c.distance(corner) < 1e-4)
+                        })
+                        .count();
+                    assert_eq!(count, 2);
+                }
+            }
+            // Angles are compared modulo `TAU`
+            let angle_eq = |a: f32, b: f32| {
+                let diff = (a - b).rem_euclid(TAU);
+                diff < 1e-4 || TAU - diff < 1e-4
+            };
+            for dir in Direction::iter() {
+                let expected = layout.direction_angle(dir);
+                assert!(angle_eq(expected, dir.angle_flat() - angle));
+                assert!(angle_eq(expected, layout.orientation.direction_angle(dir)));
+            }
+        }
+    }
+}
@@ -0,0 +1,497 @@
+use crate::{hex::ExactSizeHexIterator, Hex, HexLayout};
+use glam::Vec2;
+use std::ops::RangeInclusive;
+
+/// Rasterization mode, defining which coordinates are considered inside a shape, see
+/// [`HexLayout::rasterize_polygon`].
+///
+/// Shapes are rasterized row by row: for every hexagon row (constant `y`) the shape is
+/// intersected with the line passing through the row centers, which gives the covered
+/// coordinate intervals directly, without testing every coordinate of a bounding area.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+pub enum RasterMode {
+    /// Coordinates whose center is inside the shape
+    #[default]
+    Centers,
+    /// Coordinates whose cell, delimited by [`HexLayout::hex_corners`], overlaps the shape
+    Cells,
+}
+
+/// The line passing through all centers of a hexagon row
+#[derive(Debug, Clone, Copy)]
+struct RowLine {
+    /// World position of the row coordinate with `x = 0`
+    start: Vec2,
+    /// World offset between two consecutive row coordinates
+    step: Vec2,
+}
+
+impl RowLine {
+    /// Computes the `t` parameter interval of the row line inside the circle
+    fn circle(self, center: Vec2, radius: f32) -> Option<[f32; 2]> {
+        let offset = self.start - center;
+        let a = self.step.length_squared();
+        let b = 2.0 * self.step.dot(offset);
+        let c = radius.mul_add(-radius, offset.length_squared());
+        let discriminant = b.mul_add(b, -4.0 * a * c);
+        (discriminant >= 0.0).then(|| {
+            let root = discriminant.sqrt();
+            [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+        })
+    }
+
+    /// Computes the `t` parameter interval of the row line inside the capsule
+    fn capsule(self, start: Vec2, end: Vec2, radius: f32) -> Option<[f32; 2]> {
+        let length = start.distance(end);
+        let circles = [self.circle(start, radius), self.circle(end, radius)];
+        let body = (length > f32::EPSILON)
+            .then(|| {
+                let dir = (end - start) / length;
+                let offset = self.start - start;
+                // Solves `0 <= offset.dir <= length` and `-radius <= offset.perp <= radius`
+                [(dir, 0.0, length), (dir.perp(), -radius, radius)]
+                    .into_iter()
+                    .try_fold([f32::MIN, f32::MAX], |[lo, hi], (axis, min, max)| {
+                        let (from, to) = (min - offset.dot(axis), max - offset.dot(axis));
+                        let speed = self.step.dot(axis);
+                        if speed.abs() <= f32::EPSILON {
+                            (from <= 0.0 && to >= 0.0).then_some([lo, hi])
+                        } else {
+                            let (a, b) = (from / speed, to / speed);
+                            Some([lo.max(a.min(b)), hi.min(a.max(b))])
+                        }
+                    })
+                    .filter(|[lo, hi]| lo <= hi)
+            })
+            .flatten();
+        // The capsule is convex so the union of its parts is a single interval
+        circles
+            .into_iter()
+            .chain([body])
+            .flatten()
+            .reduce(|[a, b], [c, d]| [a.min(c), b.max(d)])
+    }
+
+    /// Computes the `t` parameter intervals of the row line inside the polygon, following
+    /// the *even-odd* rule
+    fn polygon(self, polygon: &[Vec2], intervals: &mut Vec<[f32; 2]>) {
+        let normal = self.step.perp();
+        let mut crossings: Vec<f32> = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .filter_map(|(&a, &b)| {
+                let (da, db) = ((a - self.start).dot(normal), (b - self.start).dot(normal));
+                ((da > 0.0) != (db > 0.0)).then(|| {
+                    let point = a + (b - a) * (da / (da - db));
+                    (point - self.start).dot(self.step) / self.step.length_squared()
+                })
+            })
+            .collect();
+        crossings.sort_by(f32::total_cmp);
+        intervals.extend(crossings.chunks_exact(2).map(|c| [c[0], c[1]]));
+    }
+}
+
+/// Computes the coordinate interval included in the `t` parameter `interval`
+#[allow(clippy::cast_possible_truncation)]
+fn coord_interval([lo, hi]: [f32; 2]) -> Option<[i32; 2]> {
+    let [lo, hi] = [lo.ceil() as i32, hi.floor() as i32];
+    (lo <= hi).then_some([lo, hi])
+}
+
+/// Iterates over the coordinates of the `(y, [min_x, max_x])` row intervals
+#[allow(clippy::cast_sign_loss)]
+fn row_hexes(rows: Vec<(i32, [i32; 2])>) -> impl ExactSizeIterator<Item = Hex> {
+    let count = rows
+        .iter()
+        .map(|(_, [lo, hi])| (hi - lo) as usize + 1)
+        .sum();
+    ExactSizeHexIterator {
+        iter: rows
+            .into_iter()
+            .flat_map(|(y, [lo, hi])| (lo..=hi).map(move |x| Hex::new(x, y))),
+        count,
+    }
+}
+
+/// Computes the distance between the point `p` and the segment `a`-`b`
+pub(crate) fn point_segment_distance(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let t = if ab.length_squared() > 0.0 {
+        ((p - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    p.distance(a + ab * t)
+}
+
+/// Computes the distance between the segments `a`-`b` and `c`-`d`
+fn segment_distance([a, b]: [Vec2; 2], [c, d]: [Vec2; 2]) -> f32 {
+    let [o1, o2] = [(b - a).perp_dot(c - a), (b - a).perp_dot(d - a)];
+    let [o3, o4] = [(d - c).perp_dot(a - c), (d - c).perp_dot(b - c)];
+    let collinear = o1 == 0.0 && o2 == 0.0;
+    if !collinear && o1 * o2 <= 0.0 && o3 * o4 <= 0.0 {
+        return 0.0;
+    }
+    [
+        point_segment_distance(a, c, d),
+        point_segment_distance(b, c, d),
+        point_segment_distance(c, a, b),
+        point_segment_distance(d, a, b),
+    ]
+    .into_iter()
+    .fold(f32::MAX, f32::min)
+}
+
+impl HexLayout {
+    /// Computes the range of rows (`y` coordinates) whose centers may be in the world/pixel
+    /// rectangle between `min` and `max`.
+    ///
+    /// The range is empty if the rectangle is inverted (`min > max` on any axis) or not finite,
+    /// as for an empty polygon or a `NaN` radius.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn row_range(&self, min: Vec2, max: Vec2) -> RangeInclusive<i32> {
+        #[allow(clippy::reversed_empty_ranges)]
+        if !min.is_finite() || !max.is_finite() || min.cmpgt(max).any() {
+            return 1..=0;
+        }
+        let rows = [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)]
+            .map(|p| self.world_pos_to_fract_hex(p).y);
+        let min_row = rows.iter().copied().fold(f32::MAX, f32::min).floor() as i32;
+        let max_row = rows.iter().copied().fold(f32::MIN, f32::max).ceil() as i32;
+        min_row..=max_row
+    }
+
+    /// Computes the line through the centers of the row `y`
+    fn row_line(&self, y: i32) -> RowLine {
+        RowLine {
+            start: self.hex_to_world_pos(Hex::new(0, y)),
+            step: self.hex_to_world_pos(Hex::X) - self.origin,
+        }
+    }
+
+    /// Computes the distance between the cell of `hex` and the segment `a`-`b`, which is
+    /// zero if they overlap
+    fn cell_segment_distance(&self, hex: Hex, a: Vec2, b: Vec2) -> f32 {
+        let corners = self.hex_corners(hex);
+        let edges = || corners.iter().zip(corners.iter().cycle().skip(1));
+        // The cell is convex, so `a` is inside if it is on the same side of every edge
+        let sides = edges().map(|(&c, &d)| (d - c).perp_dot(a - c));
+        let inside = sides.clone().all(|s| s >= 0.0) || sides.clone().all(|s| s <= 0.0);
+        if inside {
+            return 0.0;
+        }
+        edges()
+            .map(|(&c, &d)| segment_distance([a, b], [c, d]))
+            .fold(f32::MAX, f32::min)
+    }
+
+    /// Rasterizes rows between `min` and `max`, `row` filling the coordinate intervals of a
+    /// given row. Intervals are merged so that every coordinate is yielded once.
+    fn rasterize_rows(
+        &self,
+        min: Vec2,
+        max: Vec2,
+        mut row: impl FnMut(i32, RowLine, &mut Vec<[i32; 2]>),
+    ) -> Vec<(i32, [i32; 2])> {
+        let mut rows = Vec::new();
+        let mut intervals = Vec::new();
+        for y in self.row_range(min, max) {
+            intervals.clear();
+            row(y, self.row_line(y), &mut intervals);
+            intervals.sort_unstable();
+            let mut merged: Option<[i32; 2]> = None;
+            for &[lo, hi] in &intervals {
+                merged = match merged {
+                    Some([a, b]) if lo <= b.saturating_add(1) => Some([a, b.max(hi)]),
+                    Some(interval) => {
+                        rows.push((y, interval));
+                        Some([lo, hi])
+                    }
+                    None => Some([lo, hi]),
+                };
+            }
+            rows.extend(merged.map(|interval| (y, interval)));
+        }
+        rows
+    }
+
+    /// Rasterizes the capsule row `y` in cells mode, appending the overlapping coordinate
+    /// interval to `intervals`
+    fn capsule_cells(
+        &self,
+        (y, line): (i32, RowLine),
+        [start, end]: [Vec2; 2],
+        radius: f32,
+        intervals: &mut Vec<[i32; 2]>,
+    ) {
+        // Overlapping cells have their center in the capsule extended by the cell size
+        let Some([mut lo, mut hi]) = line
+            .capsule(start, end, radius + self.outer_radius())
+            .and_then(coord_interval)
+        else {
+            return;
+        };
+        // Overlapping cells of a row are contiguous as the capsule is convex
+        let overlaps = |x| self.cell_segment_distance(Hex::new(x, y), start, end) <= radius;
+        while lo <= hi && !overlaps(lo) {
+            lo += 1;
+        }
+        while hi >= lo && !overlaps(hi) {
+            hi -= 1;
+        }
+        if lo <= hi {
+            intervals.push([lo, hi]);
+        }
+    }
+
+    /// Computes all hexagonal coordinates inside the world/pixel space `polygon`, using
+    /// scanline rasterization. The `polygon` is implicitly closed and filled following the
+    /// *even-odd* rule, so it may be concave or self intersecting.
+    ///
+    /// See [`RasterMode`] for the inclusion rules. Coordinates are ordered by row (`y`), then
+    /// by `x`.
+    /// Nothing is yielded if the `polygon` is empty or has non finite vertices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let triangle = [Vec2::new(-0.5, -0.5), Vec2::new(3.5, -0.5), Vec2::new(-0.5, 3.5)];
+    /// let centers = layout.rasterize_polygon(&triangle, RasterMode::Centers);
+    /// let cells = layout.rasterize_polygon(&triangle, RasterMode::Cells);
+    /// assert!(centers.len() < cells.len());
+    /// ```
+    #[must_use]
+    pub fn rasterize_polygon(
+        &self,
+        polygon: &[Vec2],
+        mode: RasterMode,
+    ) -> impl ExactSizeIterator<Item = Hex> {
+        if polygon.is_empty() || !polygon.iter().all(|p| p.is_finite()) {
+            return row_hexes(Vec::new());
+        }
+        let (mut min, mut max) = polygon.iter().fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(a, b), &p| (a.min(p), b.max(p)),
+        );
+        if mode == RasterMode::Cells {
+            let extent = Vec2::splat(self.outer_radius());
+            (min, max) = (min - extent, max + extent);
+        }
+        let mut line_intervals = Vec::new();
+        let rows = self.rasterize_rows(min, max, |y, line, intervals| {
+            line_intervals.clear();
+            line.polygon(polygon, &mut line_intervals);
+            intervals.extend(line_intervals.iter().copied().filter_map(coord_interval));
+            if mode == RasterMode::Cells {
+                // Cells overlapping the polygon contain its center or cross its boundary
+                for (&a, &b) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+                    self.capsule_cells((y, line), [a, b], 0.0, intervals);
+                }
+            }
+        });
+        row_hexes(rows)
+    }
+
+    /// Computes all hexagonal coordinates inside the world/pixel space circle of given
+    /// `center` and `radius`, using scanline rasterization.
+    ///
+    /// See [`RasterMode`] for the inclusion rules. Coordinates are ordered by row (`y`), then
+    /// by `x`.
+    /// Nothing is yielded if `center` or `radius` are not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let center = layout.hex_to_world_pos(hex(3, 2));
+    /// let hexes: Vec<Hex> = layout.rasterize_circle(center, 1.0, RasterMode::Centers).collect();
+    /// assert_eq!(hexes, vec![hex(3, 2)]);
+    /// let hexes = layout.rasterize_circle(center, 2.0, RasterMode::Centers);
+    /// assert_eq!(hexes.len(), 7);
+    /// ```
+    #[must_use]
+    pub fn rasterize_circle(
+        &self,
+        center: Vec2,
+        radius: f32,
+        mode: RasterMode,
+    ) -> impl ExactSizeIterator<Item = Hex> {
+        self.rasterize_capsule(center, center, radius, mode)
+    }
+
+    /// Computes all hexagonal coordinates inside the world/pixel space capsule of given
+    /// `radius` around the segment between `start` and `end`, using scanline rasterization.
+    ///
+    /// See [`RasterMode`] for the inclusion rules. Coordinates are ordered by row (`y`), then
+    /// by `x`.
+    /// Nothing is yielded if the coordinates or `radius` are not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let [start, end] = [hex(0, 0), hex(4, 0)].map(|h| layout.hex_to_world_pos(h));
+    /// let hexes: Vec<Hex> = layout
+    ///     .rasterize_capsule(start, end, 0.5, RasterMode::Centers)
+    ///     .collect();
+    /// assert_eq!(hexes, Hex::ZERO.line_to(hex(4, 0)).collect::<Vec<_>>());
+    /// ```
+    #[must_use]
+    pub fn rasterize_capsule(
+        &self,
+        start: Vec2,
+        end: Vec2,
+        radius: f32,
+        mode: RasterMode,
+    ) -> impl ExactSizeIterator<Item = Hex> {
+        let extent = Vec2::splat(match mode {
+            RasterMode::Centers => radius,
+            RasterMode::Cells => radius + self.outer_radius(),
+        });
+        let (min, max) = (start.min(end) - extent, start.max(end) + extent);
+        let rows = self.rasterize_rows(min, max, |y, line, intervals| match mode {
+            RasterMode::Centers => {
+                intervals.extend(line.capsule(start, end, radius).and_then(coord_interval));
+            }
+            RasterMode::Cells => self.capsule_cells((y, line), [start, end], radius, intervals),
+        });
+        row_hexes(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HexOrientation;
+
+    fn layouts() -> [HexLayout; 3] {
+        [
+            HexLayout::default(),
+            HexLayout {
+                orientation: HexOrientation::pointy(),
+                hex_size: Vec2::new(1.5, 0.8),
+                origin: Vec2::new(0.3, -2.0),
+            },
+            HexLayout {
+                orientation: HexOrientation::from_angle(0.4),
+                ..Default::default()
+            }
+            .with_inverted_x(true),
+        ]
+    }
+
+    /// Even-odd point in polygon test
+    fn in_polygon(p: Vec2, polygon: &[Vec2]) -> bool {
+        polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .filter(|(a, b)| {
+                (a.y > p.y) != (b.y > p.y) && p.x < a.x + (b.x - a.x) * (p.y - a.y) / (b.y - a.y)
+            })
+            .count()
+            % 2
+            == 1
+    }
+
+    fn assert_raster(
+        layout: &HexLayout,
+        iter: impl ExactSizeIterator<Item = Hex>,
+        expected: impl Fn(Hex) -> bool,
+    ) -> Vec<Hex> {
+        let len = iter.len();
+        let hexes: Vec<_> = iter.collect();
+        assert_eq!(hexes.len(), len);
+        let mut brute: Vec<_> = Hex::ZERO.range(25).filter(|h| expected(*h)).collect();
+        brute.sort_by_key(|h| (h.y, h.x));
+        assert_eq!(hexes, brute, "{layout:?}");
+        hexes
+    }
+
+    #[test]
+    fn polygon() {
+        // Concave "U" shape
+        let polygon = [
+            Vec2::new(-4.2, -3.1),
+            Vec2::new(5.3, -3.0),
+            Vec2::new(5.1, 4.2),
+            Vec2::new(2.9, 4.4),
+            Vec2::new(3.0, -0.7),
+            Vec2::new(-1.6, -0.9),
+            Vec2::new(-2.0, 4.1),
+            Vec2::new(-4.4, 3.8),
+        ];
+        for layout in layouts() {
+            let centers = layout.rasterize_polygon(&polygon, RasterMode::Centers);
+            let centers = assert_raster(&layout, centers, |h| {
+                in_polygon(layout.hex_to_world_pos(h), &polygon)
+            });
+            let cells = layout.rasterize_polygon(&polygon, RasterMode::Cells);
+            let cells = assert_raster(&layout, cells, |h| {
+                in_polygon(layout.hex_to_world_pos(h), &polygon)
+                    || polygon
+                        .iter()
+                        .zip(polygon.iter().cycle().skip(1))
+                        .any(|(&a, &b)| layout.cell_segment_distance(h, a, b) <= 0.0)
+            });
+            assert!(centers.iter().all(|h| cells.contains(h)));
+            for p in polygon {
+                assert!(cells.contains(&layout.world_pos_to_hex(p)));
+            }
+        }
+    }
+
+    #[test]
+    fn circle_and_capsule() {
+        let [a, b] = [Vec2::new(-3.3, 1.2), Vec2::new(4.1, -2.7)];
+        for layout in layouts() {
+            for radius in [0.0, 0.7, 2.5] {
+                let circle = layout.rasterize_circle(a, radius, RasterMode::Centers);
+                assert_raster(&layout, circle, |h| {
+                    layout.hex_to_world_pos(h).distance(a) <= radius
+                });
+                let circle = layout.rasterize_circle(a, radius, RasterMode::Cells);
+                let circle = assert_raster(&layout, circle, |h| {
+                    layout.cell_segment_distance(h, a, a) <= radius
+                });
+                assert!(circle.contains(&layout.world_pos_to_hex(a)));
+                let capsule = layout.rasterize_capsule(a, b, radius, RasterMode::Centers);
+                assert_raster(&layout, capsule, |h| {
+                    point_segment_distance(layout.hex_to_world_pos(h), a, b) <= radius
+                });
+                let capsule = layout.rasterize_capsule(a, b, radius, RasterMode::Cells);
+                let capsule = assert_raster(&layout, capsule, |h| {
+                    layout.cell_segment_distance(h, a, b) <= radius
+                });
+                for t in [0.0, 0.1, 0.25, 0.5, 0.8, 1.0] {
+                    let p = a.lerp(b, t);
+                    assert!(capsule.contains(&layout.world_pos_to_hex(p)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_shapes() {
+        let [a, b] = [Vec2::new(-3.3, 1.2), Vec2::new(4.1, -2.7)];
+        for layout in layouts() {
+            for mode in [RasterMode::Centers, RasterMode::Cells] {
+                assert_eq!(layout.rasterize_polygon(&[], mode).len(), 0);
+                let polygon = [a, Vec2::new(f32::NAN, 0.0), b];
+                assert_eq!(layout.rasterize_polygon(&polygon, mode).len(), 0);
+                for radius in [f32::NAN, f32::INFINITY, -f32::INFINITY, -50.0] {
+                    assert_eq!(layout.rasterize_circle(a, radius, mode).len(), 0);
+                    assert_eq!(layout.rasterize_capsule(a, b, radius, mode).len(), 0);
+                }
+                let center = Vec2::new(f32::INFINITY, 0.0);
+                assert_eq!(layout.rasterize_circle(center, 1.0, mode).len(), 0);
+                assert_eq!(layout.rasterize_capsule(a, center, 1.0, mode).len(), 0);
+            }
+        }
+    }
+}
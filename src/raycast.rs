@@ -0,0 +1,322 @@
+use crate::{Direction, FractionalHex, Hex, HexLayout};
+use glam::{Vec2, Vec3};
+use std::iter;
+
+/// A cell traversed by a ray, see [`HexLayout::raycast`].
+///
+/// Contrary to [`Hex::line_to`], which links hexagon centers, [`HexLayout::raycast`] follows an
+/// arbitrary world/pixel space ray and returns every cell it passes through, in order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+pub struct RayHit {
+    /// The traversed hexagonal coordinates
+    pub hex: Hex,
+    /// The world/pixel distance along the ray at which it enters the cell
+    pub entry: f32,
+    /// The world/pixel distance along the ray at which it exits the cell, or the maximum ray
+    /// distance if the ray stops inside the cell
+    pub exit: f32,
+    /// The edge of the cell crossed by the ray to enter it, pointing towards the previous cell.
+    ///
+    /// `None` for the cell containing the ray origin
+    pub entry_edge: Option<Direction>,
+    /// The edge of the cell crossed by the ray to exit it, pointing towards the next cell.
+    ///
+    /// `None` if the ray stops inside the cell
+    pub exit_edge: Option<Direction>,
+}
+
+/// The face of an hexagonal column hit by a 3D ray, see [`HexLayout::column_raycast`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColumnFace {
+    /// The top face of the column
+    Top,
+    /// The bottom face of the column
+    Bottom,
+    /// The side face of the column in the given direction
+    Side(Direction),
+}
+
+/// An hexagonal column hit by a 3D ray, see [`HexLayout::column_raycast`].
+///
+/// Column raycasts extend the [`HexLayout::raycast`] traversal to 3D hexagonal columns, for
+/// mouse picking.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnHit {
+    /// The coordinates of the hit column
+    pub hex: Hex,
+    /// The hit face of the column
+    pub face: ColumnFace,
+    /// The 3D world position of the hit
+    pub point: Vec3,
+    /// The distance along the ray of the hit
+    pub distance: f32,
+}
+
+/// Dot product in cubic coordinates
+fn cubic_dot(a: FractionalHex, b: Hex) -> f32 {
+    let [ax, ay, az] = a.to_cubic_array();
+    let [bx, by, bz] = b.as_fractional().to_cubic_array();
+    az.mul_add(bz, ax.mul_add(bx, ay * by))
+}
+
+impl HexLayout {
+    /// Traverses all cells crossed by the world/pixel ray starting at `origin` with direction
+    /// `direction`, up to `max_distance`, in order.
+    ///
+    /// Every [`RayHit`] gives the world distances, along the normalized `direction`, at which
+    /// the ray enters and exits the cell, and the crossed edges. The traversal is incremental,
+    /// so it works on unbounded grids and stops early when the iterator is dropped.
+    ///
+    /// If the ray passes exactly through a cell corner, one of the cells sharing the corner
+    /// may be returned with an `entry` equal to its `exit`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout {
+    ///     orientation: HexOrientation::pointy(),
+    ///     ..Default::default()
+    /// };
+    /// let hits: Vec<RayHit> = layout.raycast(Vec2::ZERO, Vec2::X, 4.0).collect();
+    /// assert_eq!(hits.len(), 3);
+    /// assert_eq!(hits[1].hex, hex(1, 0));
+    /// assert_eq!(hits[1].entry_edge, Some(Direction::TopLeft));
+    /// assert_eq!(hits[1].exit_edge, Some(Direction::BottomRight));
+    /// assert_eq!(hits[2].exit, 4.0);
+    /// ```
+    pub fn raycast(
+        &self,
+        origin: Vec2,
+        direction: Vec2,
+        max_distance: f32,
+    ) -> impl Iterator<Item = RayHit> {
+        let start = self.world_pos_to_fract_hex(origin);
+        // The world to hexagonal conversion is affine, so distances along the ray are kept
+        let step = self.world_pos_to_fract_hex(origin + direction.normalize_or_zero()) - start;
+        let traverse = move |hex: Hex, entry: f32, entry_edge: Option<Direction>| {
+            // The cell is bounded by the planes `p.v = 1` in cubic coordinates, for every
+            // neighbor coordinate `v`
+            let offset = start - hex;
+            let exit = Direction::ALL_DIRECTIONS
+                .into_iter()
+                .filter_map(|dir| {
+                    let v = Hex::neighbor_coord(dir);
+                    let speed = cubic_dot(step, v);
+                    (speed > 0.0).then(|| ((1.0 - cubic_dot(offset, v)) / speed, dir))
+                })
+                .min_by(|(a, _), (b, _)| a.total_cmp(b))
+                .map(|(distance, dir)| (distance.max(entry), dir))
+                .filter(|(distance, _)| *distance < max_distance);
+            RayHit {
+                hex,
+                entry,
+                exit: exit.map_or(max_distance, |(distance, _)| distance),
+                entry_edge,
+                exit_edge: exit.map(|(_, dir)| dir),
+            }
+        };
+        let first = traverse(start.round(), 0.0, None);
+        iter::successors(Some(first), move |prev| {
+            let dir = prev.exit_edge?;
+            Some(traverse(prev.hex.neighbor(dir), prev.exit, Some(-dir)))
+        })
+    }
+
+    /// Finds the first hexagonal column hit by the 3D ray starting at `origin` with direction
+    /// `direction`, up to `max_distance`.
+    ///
+    /// Columns follow the [`MeshInfo`](crate::MeshInfo) conventions: the layout world
+    /// position `(x, y)` maps to `(x, z)` in 3D, and columns span vertically along the `y` axis
+    /// over the `[base, top]` interval given by `column`, which returns `None` for coordinates
+    /// without column.
+    ///
+    /// Cells are traversed in order along the ray (see [`Self::raycast`]), so the cost only
+    /// depends on the number of crossed cells. A ray starting inside a column ignores it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// # use glam::Vec3;
+    /// let layout = HexLayout::default();
+    /// let column = |hex: Hex| Some([-1.0, hex.length() as f32]);
+    /// // Looking down
+    /// let hit = layout
+    ///     .column_raycast(Vec3::new(0.0, 10.0, 0.0), Vec3::NEG_Y, 100.0, column)
+    ///     .unwrap();
+    /// assert_eq!(hit.hex, Hex::ZERO);
+    /// assert_eq!(hit.distance, 10.0);
+    /// assert_eq!(hit.face, ColumnFace::Top);
+    /// assert_eq!(hit.point, Vec3::ZERO);
+    /// // Looking at the side of a column
+    /// let hit = layout
+    ///     .column_raycast(Vec3::new(0.0, 0.5, 0.0), Vec3::Z, 100.0, column)
+    ///     .unwrap();
+    /// assert_eq!(hit.hex, hex(0, 1));
+    /// assert_eq!(hit.face, ColumnFace::Side(Direction::Top));
+    /// // Looking up
+    /// let hit = layout
+    ///     .column_raycast(Vec3::new(0.0, -5.0, 0.0), Vec3::Y, 100.0, column)
+    ///     .unwrap();
+    /// assert_eq!(hit.face, ColumnFace::Bottom);
+    /// ```
+    pub fn column_raycast(
+        &self,
+        origin: Vec3,
+        direction: Vec3,
+        max_distance: f32,
+        column: impl Fn(Hex) -> Option<[f32; 2]>,
+    ) -> Option<ColumnHit> {
+        let direction = direction.normalize_or_zero();
+        let mut direction_2d = Vec2::new(direction.x, direction.z);
+        // Ratio between the horizontal distance and the 3D distance along the ray
+        let mut speed = direction_2d.length();
+        if speed <= f32::EPSILON {
+            // Vertical ray
+            (direction_2d, speed) = (Vec2::ZERO, 1.0);
+        }
+        let origin_2d = Vec2::new(origin.x, origin.z);
+        self.raycast(origin_2d, direction_2d, max_distance * speed)
+            .find_map(|hit| {
+                let (entry, exit) = (hit.entry / speed, hit.exit / speed);
+                let [base, top] = column(hit.hex)?;
+                let hit_at = |distance: f32, face| ColumnHit {
+                    hex: hit.hex,
+                    face,
+                    point: origin + direction * distance,
+                    distance,
+                };
+                let entry_y = direction.y.mul_add(entry, origin.y);
+                if let Some(edge) = hit.entry_edge {
+                    if (base..=top).contains(&entry_y) {
+                        return Some(hit_at(entry, ColumnFace::Side(edge)));
+                    }
+                }
+                let (plane, face) = if entry_y > top && direction.y < 0.0 {
+                    (top, ColumnFace::Top)
+                } else if entry_y < base && direction.y > 0.0 {
+                    (base, ColumnFace::Bottom)
+                } else {
+                    return None;
+                };
+                let distance = (plane - origin.y) / direction.y;
+                (distance <= exit).then(|| hit_at(distance, face))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HexOrientation;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn consistent_traversal() {
+        let layouts = [
+            HexLayout::default(),
+            HexLayout {
+                orientation: HexOrientation::pointy(),
+                hex_size: Vec2::new(1.7, 0.6),
+                origin: Vec2::new(-0.4, 2.0),
+            }
+            .with_inverted_y(true),
+            HexLayout {
+                orientation: HexOrientation::from_angle(1.1),
+                ..Default::default()
+            },
+        ];
+        let rays = [
+            (Vec2::new(0.1, 0.2), Vec2::new(1.0, 0.37)),
+            (Vec2::new(-3.3, 5.1), Vec2::new(-0.2, -1.0)),
+            (Vec2::new(2.0, -1.0), Vec2::new(3.0, 4.0)),
+        ];
+        for layout in &layouts {
+            for (origin, direction) in rays {
+                let hits: Vec<_> = layout.raycast(origin, direction, 20.0).collect();
+                assert_eq!(hits[0].hex, layout.world_pos_to_hex(origin));
+                assert_eq!(hits[0].entry, 0.0);
+                assert_eq!(hits.last().unwrap().exit, 20.0);
+                assert_eq!(hits.last().unwrap().exit_edge, None);
+                for pair in hits.windows(2) {
+                    let [a, b] = [pair[0], pair[1]];
+                    assert_eq!(a.exit, b.entry);
+                    assert!(a.entry < a.exit);
+                    assert_eq!(a.hex.neighbor(a.exit_edge.unwrap()), b.hex);
+                    assert_eq!(b.entry_edge, a.exit_edge.map(|d| -d));
+                }
+                // Every sampled point along the ray is in the matching cell
+                let direction = direction.normalize();
+                for hit in &hits {
+                    let middle = origin + direction * (hit.entry + hit.exit) / 2.0;
+                    assert_eq!(layout.world_pos_to_hex(middle), hit.hex);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zero_direction() {
+        let layout = HexLayout::default();
+        let hits: Vec<_> = layout
+            .raycast(Vec2::new(3.0, 1.0), Vec2::ZERO, 5.0)
+            .collect();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].hex, layout.world_pos_to_hex(Vec2::new(3.0, 1.0)));
+        assert_eq!(hits[0].exit_edge, None);
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn column_raycast() {
+        let layout = HexLayout {
+            hex_size: Vec2::new(1.0, 1.3),
+            ..Default::default()
+        };
+        let column = |hex: Hex| {
+            let base = hex.y.rem_euclid(3) as f32 - 1.0;
+            (hex.x > -4).then_some([base, base + (hex.x + hex.y * 2).rem_euclid(5) as f32 + 0.5])
+        };
+        let rays = [
+            (Vec3::new(-6.0, 8.0, -3.0), Vec3::new(1.0, -0.8, 0.4)),
+            (Vec3::new(5.0, 7.0, 1.0), Vec3::new(-1.0, -0.6, 0.2)),
+            (Vec3::new(0.3, 20.0, 0.2), Vec3::new(0.05, -1.0, 0.0)),
+            (Vec3::new(-2.0, -8.0, 1.0), Vec3::new(0.3, 1.0, -0.1)),
+        ];
+        for (origin, direction) in rays {
+            let hit = layout
+                .column_raycast(origin, direction, 50.0, column)
+                .unwrap();
+            let direction = direction.normalize();
+            assert!(hit.point.distance(origin + direction * hit.distance) < 1e-4);
+            // Brute force ray marching
+            let inside = |p: Vec3| {
+                let hex = layout.world_pos_to_hex(Vec2::new(p.x, p.z));
+                column(hex).is_some_and(|[base, top]| (base..=top).contains(&p.y))
+            };
+            let first = (0..50_000)
+                .map(|i| i as f32 * 0.001)
+                .find(|d| inside(origin + direction * *d))
+                .unwrap();
+            assert!((first - hit.distance).abs() < 0.002, "{first} {hit:?}");
+            let hex = layout.world_pos_to_hex(Vec2::new(hit.point.x, hit.point.z));
+            match hit.face {
+                ColumnFace::Top => {
+                    assert_eq!(hex, hit.hex);
+                    assert!((hit.point.y - column(hit.hex).unwrap()[1]).abs() < 1e-4);
+                }
+                ColumnFace::Bottom => {
+                    assert_eq!(hex, hit.hex);
+                    assert!((hit.point.y - column(hit.hex).unwrap()[0]).abs() < 1e-4);
+                }
+                ColumnFace::Side(dir) => {
+                    assert!(hex == hit.hex || hex == hit.hex.neighbor(dir));
+                }
+            }
+        }
+    }
+}
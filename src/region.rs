@@ -0,0 +1,421 @@
+use crate::{Direction, Hex, HexBounds, HexIterExt};
+use std::{
+    collections::{hash_set, HashSet},
+    hash::BuildHasher,
+};
+
+/// Arbitrary set of [`Hex`] coordinates, useful to represent territories, selections or
+/// fields of view.
+///
+/// This type provides set algebra (union, intersection, difference) and *morphology*
+/// operations (growing, shrinking, hole filling).
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+///
+/// let a: HexRegion = Hex::ZERO.range(3).collect();
+/// let b: HexRegion = hex(4, 0).range(3).collect();
+/// // Set operations
+/// let union = a.union(&b);
+/// let intersection = a.intersection(&b);
+/// assert_eq!(union.area(), a.area() + b.area() - intersection.area());
+/// // Morphology
+/// let grown = a.grow(2);
+/// assert_eq!(grown, Hex::ZERO.range(5).collect());
+/// assert_eq!(grown.shrink(2), a);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexRegion {
+    /// The region coordinates
+    coords: HashSet<Hex>,
+}
+
+impl HexRegion {
+    #[inline]
+    #[must_use]
+    /// Instantiates a new empty region
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checks if `hex` is in the region
+    pub fn contains(&self, hex: Hex) -> bool {
+        self.coords.contains(&hex)
+    }
+
+    #[inline]
+    /// Adds `hex` to the region, returning `false` if it was already present
+    pub fn insert(&mut self, hex: Hex) -> bool {
+        self.coords.insert(hex)
+    }
+
+    #[inline]
+    /// Removes `hex` from the region, returning `false` if it wasn't present
+    pub fn remove(&mut self, hex: Hex) -> bool {
+        self.coords.remove(&hex)
+    }
+
+    #[inline]
+    #[must_use]
+    #[doc(alias = "len")]
+    #[doc(alias = "hex_count")]
+    /// Returns the number of coordinates in the region
+    pub fn area(&self) -> usize {
+        self.coords.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the region has no coordinates
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an iterator over the region coordinates, in arbitrary order
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Hex> + '_ {
+        self.coords.iter().copied()
+    }
+
+    #[must_use]
+    /// Computes the union of `self` and `rhs`: coordinates in either region
+    pub fn union(&self, rhs: &Self) -> Self {
+        self.coords.union(&rhs.coords).copied().collect()
+    }
+
+    #[must_use]
+    /// Computes the intersection of `self` and `rhs`: coordinates in both regions
+    pub fn intersection(&self, rhs: &Self) -> Self {
+        self.coords.intersection(&rhs.coords).copied().collect()
+    }
+
+    #[must_use]
+    /// Computes the difference of `self` and `rhs`: coordinates in `self` but not in `rhs`
+    pub fn difference(&self, rhs: &Self) -> Self {
+        self.coords.difference(&rhs.coords).copied().collect()
+    }
+
+    #[must_use]
+    /// Computes the symmetric difference of `self` and `rhs`: coordinates in one of the regions
+    /// but not both
+    pub fn symmetric_difference(&self, rhs: &Self) -> Self {
+        self.coords
+            .symmetric_difference(&rhs.coords)
+            .copied()
+            .collect()
+    }
+
+    #[must_use]
+    #[doc(alias = "dilate")]
+    /// Grows the region by `rings` rings, adding every coordinate at a distance of at most
+    /// `rings` from the region (morphological *dilation*)
+    pub fn grow(&self, rings: u32) -> Self {
+        let mut res = self.clone();
+        let mut frontier: Vec<_> = self.iter().collect();
+        for _ in 0..rings {
+            let mut next = Vec::new();
+            for hex in frontier {
+                for neighbor in hex.all_neighbors() {
+                    if res.insert(neighbor) {
+                        next.push(neighbor);
+                    }
+                }
+            }
+            frontier = next;
+        }
+        res
+    }
+
+    #[must_use]
+    #[doc(alias = "erode")]
+    /// Shrinks the region by `rings` rings, keeping only the coordinates whose whole range of
+    /// `rings` is in the region (morphological *erosion*)
+    pub fn shrink(&self, rings: u32) -> Self {
+        let mut res = self.clone();
+        for _ in 0..rings {
+            if res.is_empty() {
+                break;
+            }
+            res = res
+                .iter()
+                .filter(|h| h.all_neighbors().iter().all(|n| res.contains(*n)))
+                .collect();
+        }
+        res
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    /// Returns the *holes* of the region: coordinates outside of the region but fully enclosed by
+    /// it
+    pub fn holes(&self) -> Self {
+        if self.is_empty() {
+            return Self::new();
+        }
+        // Every coordinate reachable from outside the bounds, without crossing the region
+        let center = self.center();
+        let radius = self
+            .iter()
+            .map(|h| h.unsigned_distance_to(center))
+            .max()
+            .unwrap_or_default();
+        let bounds = HexBounds::new(center, radius + 1);
+        let start = bounds.center + Hex::X * bounds.radius as i32;
+        let mut outside = HashSet::from([start]);
+        let mut open = vec![start];
+        while let Some(hex) = open.pop() {
+            for neighbor in hex.all_neighbors() {
+                if bounds.is_in_bounds(neighbor)
+                    && !self.contains(neighbor)
+                    && outside.insert(neighbor)
+                {
+                    open.push(neighbor);
+                }
+            }
+        }
+        bounds
+            .all_coords()
+            .filter(|h| !self.contains(*h) && !outside.contains(h))
+            .collect()
+    }
+
+    #[must_use]
+    /// Fills the *holes* of the region, see [`Self::holes`]
+    pub fn fill_holes(&self) -> Self {
+        self.union(&self.holes())
+    }
+
+    #[must_use]
+    /// Returns the mean (average) coordinate of the region.
+    ///
+    /// See [`HexIterExt::average`]
+    pub fn average(&self) -> Hex {
+        self.iter().average()
+    }
+
+    #[must_use]
+    #[doc(alias = "centroid")]
+    /// Returns the center (centroid) coordinate of the region.
+    ///
+    /// This method will return [`Hex::ZERO`] on an empty region.
+    /// See [`HexIterExt::center`]
+    pub fn center(&self) -> Hex {
+        self.iter().center()
+    }
+
+    #[must_use]
+    /// Returns the bounds of the region, centered on [`Self::center`].
+    ///
+    /// This method will return ([`Hex::ZERO`], 0) on an empty region.
+    /// See [`HexIterExt::bounds`]
+    pub fn bounds(&self) -> HexBounds {
+        self.iter().bounds()
+    }
+
+    #[must_use]
+    /// Computes the boundary of the region, as closed loops of edges.
+    ///
+    /// Each edge is represented by a coordinate of the region and the [`Direction`] of its
+    /// neighbor outside of the region. Every loop is ordered, each edge being followed by
+    /// the edge sharing its counter clockwise end, and the first edge following the last one.
+    ///
+    /// The region outer boundaries and its holes boundaries are all returned, see
+    /// [`Self::holes`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let region: HexRegion = Hex::ZERO.ring(3).collect();
+    /// let loops = region.boundary_loops();
+    /// // The outer boundary and the hole boundary
+    /// assert_eq!(loops.len(), 2);
+    /// ```
+    pub fn boundary_loops(&self) -> Vec<Vec<(Hex, Direction)>> {
+        let mut edges: Vec<_> = self
+            .iter()
+            .flat_map(|hex| {
+                Direction::iter()
+                    .filter(move |dir| !self.contains(hex.neighbor(*dir)))
+                    .map(move |dir| (hex, dir))
+            })
+            .collect();
+        // Sorting guarantees a deterministic output
+        edges.sort_unstable_by_key(|(hex, dir)| (hex.x, hex.y, *dir as u8));
+        let mut remaining: HashSet<_> = edges.iter().copied().collect();
+        let mut res = Vec::new();
+        for start in edges {
+            if !remaining.contains(&start) {
+                continue;
+            }
+            let mut boundary = Vec::new();
+            let mut edge = start;
+            while remaining.remove(&edge) {
+                boundary.push(edge);
+                let (hex, dir) = edge;
+                let next = hex.neighbor(dir.left());
+                edge = if self.contains(next) {
+                    (next, dir.right())
+                } else {
+                    (hex, dir.left())
+                };
+            }
+            res.push(boundary);
+        }
+        res
+    }
+
+    #[must_use]
+    /// Computes the boundary of the region, as a list of edges.
+    ///
+    /// See [`Self::boundary_loops`] for ordering details.
+    pub fn boundary_edges(&self) -> Vec<(Hex, Direction)> {
+        self.boundary_loops().into_iter().flatten().collect()
+    }
+}
+
+impl FromIterator<Hex> for HexRegion {
+    fn from_iter<T: IntoIterator<Item = Hex>>(iter: T) -> Self {
+        Self {
+            coords: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Hex> for HexRegion {
+    fn extend<T: IntoIterator<Item = Hex>>(&mut self, iter: T) {
+        self.coords.extend(iter);
+    }
+}
+
+impl IntoIterator for HexRegion {
+    type Item = Hex;
+    type IntoIter = hash_set::IntoIter<Hex>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coords.into_iter()
+    }
+}
+
+impl From<HashSet<Hex>> for HexRegion {
+    fn from(coords: HashSet<Hex>) -> Self {
+        Self { coords }
+    }
+}
+
+impl<S: BuildHasher + Default> From<HexRegion> for HashSet<Hex, S> {
+    fn from(region: HexRegion) -> Self {
+        region.coords.into_iter().collect()
+    }
+}
+
+impl From<HexBounds> for HexRegion {
+    fn from(bounds: HexBounds) -> Self {
+        bounds.all_coords().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let a: HexRegion = Hex::ZERO.range(3).collect();
+        let b: HexRegion = Hex::new(4, 0).range(3).collect();
+        let intersection = a.intersection(&b);
+        assert_eq!(
+            intersection,
+            HexBounds::new(Hex::ZERO, 3)
+                .intersecting_with(HexBounds::new(Hex::new(4, 0), 3))
+                .collect()
+        );
+        assert_eq!(
+            a.union(&b).area(),
+            a.area() + b.area() - intersection.area()
+        );
+        assert_eq!(a.difference(&b).area(), a.area() - intersection.area());
+        assert_eq!(
+            a.symmetric_difference(&b),
+            a.union(&b).difference(&intersection)
+        );
+    }
+
+    #[test]
+    fn morphology() {
+        let region: HexRegion = Hex::new(3, -2).range(4).collect();
+        for rings in 0..4 {
+            let expected: HexRegion = Hex::new(3, -2).range(4 + rings).collect();
+            assert_eq!(region.grow(rings), expected);
+            let expected: HexRegion = Hex::new(3, -2).range(4 - rings).collect();
+            assert_eq!(region.shrink(rings), expected);
+        }
+        assert!(region.shrink(5).is_empty());
+    }
+
+    #[test]
+    fn holes() {
+        let ring: HexRegion = Hex::ZERO.ring(4).collect();
+        assert_eq!(ring.holes(), Hex::ZERO.range(3).collect());
+        assert_eq!(ring.fill_holes(), Hex::ZERO.range(4).collect());
+        // An opened ring has no holes
+        let mut opened = ring;
+        opened.remove(Hex::new(4, 0));
+        assert!(opened.holes().is_empty());
+        // Off-center regions
+        let mut region: HexRegion = Hex::new(10, -3).ring(2).collect();
+        region.extend(Hex::ZERO.line_to(Hex::new(8, -3)));
+        assert_eq!(region.holes(), Hex::new(10, -3).range(1).collect());
+    }
+
+    #[test]
+    fn boundary() {
+        let single = HexRegion::from_iter([Hex::new(3, 4)]);
+        let loops = single.boundary_loops();
+        assert_eq!(loops.len(), 1);
+        assert_eq!(
+            loops[0],
+            Direction::iter()
+                .map(|d| (Hex::new(3, 4), d))
+                .collect::<Vec<_>>()
+        );
+        for radius in 1..10 {
+            let region: HexRegion = Hex::ZERO.range(radius).collect();
+            let loops = region.boundary_loops();
+            assert_eq!(loops.len(), 1);
+            assert_eq!(loops[0].len(), 6 * (2 * radius as usize + 1));
+            // Each edge is connected to the next one
+            for (i, (hex, dir)) in loops[0].iter().enumerate() {
+                let (next_hex, next_dir) = loops[0][(i + 1) % loops[0].len()];
+                assert!(
+                    (next_hex == *hex && next_dir == dir.left())
+                        || (next_hex == hex.neighbor(dir.left()) && next_dir == dir.right())
+                );
+            }
+        }
+        let ring: HexRegion = Hex::ZERO.ring(3).collect();
+        let loops = ring.boundary_loops();
+        assert_eq!(loops.len(), 2);
+        assert_eq!(loops[0].len() + loops[1].len(), ring.boundary_edges().len());
+        let holes: HexRegion = ring.holes();
+        let hole_loops = holes.boundary_loops();
+        assert_eq!(hole_loops.len(), 1);
+        assert!(loops.iter().any(|l| l.len() == hole_loops[0].len()));
+    }
+
+    #[test]
+    fn bounds() {
+        let bounds = HexBounds::new(Hex::new(-5, 2), 6);
+        let region = HexRegion::from(bounds);
+        assert_eq!(region.area(), bounds.hex_count());
+        assert_eq!(region.center(), bounds.center);
+        assert_eq!(region.bounds().radius, bounds.radius);
+        assert_eq!(HexRegion::new().bounds().radius, 0);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{hex::ExactSizeHexIterator, Hex};
+use crate::{hex::ExactSizeHexIterator, Hex, HexOrientation};
+use glam::Vec2;
+use std::f32::consts::{PI, TAU};
 
 /// Generates a parallelogram layout from `min` to `max`
 #[must_use]
@@ -33,6 +35,74 @@ pub fn hexagon(center: Hex, radius: u32) -> impl ExactSizeIterator<Item = Hex> {
     center.range(radius)
 }
 
+/// Generates a capsule from `start` to `end` with a custom `radius`.
+///
+/// See [`Hex::thick_line_to`] for the coordinates order
+#[must_use]
+pub fn capsule(start: Hex, end: Hex, radius: u32) -> impl ExactSizeIterator<Item = Hex> {
+    start.thick_line_to(end, radius)
+}
+
+/// Generates a cone (circular sector) around `center` with a custom `range`.
+///
+/// The cone faces `facing`, an angle in radians in world/pixel space under the given
+/// `orientation`, and spans `aperture` radians (`aperture / 2` on each side of `facing`).
+/// All coordinates whose centers fall in the sector are returned, including `center`.
+///
+/// See [`cone_towards`] to use a direction vector instead of an angle.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use std::f32::consts::PI;
+///
+/// // 90 degrees cone facing the world space `x` axis
+/// let cone: Vec<Hex> = shapes::cone(Hex::ZERO, 10, &HexOrientation::flat(), 0.0, PI / 2.0).collect();
+/// ```
+pub fn cone(
+    center: Hex,
+    range: u32,
+    orientation: &HexOrientation,
+    facing: f32,
+    aperture: f32,
+) -> impl Iterator<Item = Hex> {
+    // Tolerance for coordinates exactly on the sector boundaries
+    const EPSILON: f32 = 1e-4;
+    let orientation = *orientation;
+    let half_aperture = aperture / 2.0 + EPSILON;
+    center.range(range).filter(move |h| {
+        if *h == center || aperture >= TAU {
+            return true;
+        }
+        let pos = orientation.forward((*h - center).as_vec2());
+        let delta = (pos.y.atan2(pos.x) - facing + PI).rem_euclid(TAU) - PI;
+        delta.abs() <= half_aperture
+    })
+}
+
+/// Generates a cone (circular sector) around `center` with a custom `range`.
+///
+/// The cone faces `direction`, a world/pixel space vector under the given `orientation`,
+/// and spans `aperture` radians (`aperture / 2` on each side of `direction`).
+///
+/// See [`cone`] for more information.
+pub fn cone_towards(
+    center: Hex,
+    range: u32,
+    orientation: &HexOrientation,
+    direction: Vec2,
+    aperture: f32,
+) -> impl Iterator<Item = Hex> {
+    cone(
+        center,
+        range,
+        orientation,
+        direction.y.atan2(direction.x),
+        aperture,
+    )
+}
+
 /// Generates a rectangle with the given bounds for "pointy topped" hexagons.
 ///
 /// The function takes four offsets as `[left, right, top, bottom]`.
@@ -74,6 +144,8 @@ pub fn flat_rectangle([left, right, top, bottom]: [i32; 4]) -> impl ExactSizeIte
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{DiagonalDirection, Direction};
+    use std::collections::HashSet;
 
     #[test]
     fn hexagon_test() {
@@ -84,6 +156,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cone_test() {
+        let center = Hex::new(2, -5);
+        let orientation = HexOrientation::flat();
+        // Full aperture cones are hexagons
+        let full: HashSet<_> = cone(center, 10, &orientation, 1.0, TAU).collect();
+        assert_eq!(full, hexagon(center, 10).collect());
+        // 120 degrees cones match the directional wedges
+        for dir in Direction::iter() {
+            let facing = orientation.forward(Hex::from(dir).as_vec2());
+            let cone: HashSet<_> =
+                cone_towards(center, 10, &orientation, facing, PI * 2.0 / 3.0).collect();
+            let wedge: HashSet<_> = hexagon(center, 10)
+                .filter(|h| {
+                    let way = center.diagonal_way_to(*h);
+                    *h == center || way == dir.diagonal_left() || way == dir.diagonal_right()
+                })
+                .collect();
+            assert_eq!(cone, wedge);
+        }
+        // Narrow cones are lines
+        let line: Vec<_> = cone(center, 10, &orientation, 0.0, 0.0).collect();
+        let diagonal = Hex::from(DiagonalDirection::Right);
+        assert_eq!(
+            line,
+            (0..=5).map(|i| center + diagonal * i).collect::<Vec<_>>()
+        );
+        let facing = orientation.forward(Hex::new(1, -1).as_vec2());
+        let mut line: Vec<_> = cone_towards(center, 5, &orientation, facing, 0.0).collect();
+        line.sort_by_key(|h| h.x);
+        assert_eq!(
+            line,
+            center.line_to(center + Hex::new(5, -5)).collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn triangle_test() {
         for range in 0..=30 {
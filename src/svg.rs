@@ -0,0 +1,322 @@
+use crate::{Hex, HexLayout, HexRegion};
+use glam::Vec2;
+use std::fmt::{self, Display, Formatter, Write};
+
+/// Style of a single hexagon drawn by [`SvgWriter`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HexStyle {
+    /// SVG fill color, `None` for no fill
+    pub fill: Option<String>,
+    /// SVG stroke color, `None` for no stroke
+    pub stroke: Option<String>,
+    /// Stroke width, in world/pixel units
+    pub stroke_width: f32,
+    /// Text drawn at the center of the hexagon
+    pub label: Option<String>,
+}
+
+impl Default for HexStyle {
+    fn default() -> Self {
+        Self {
+            fill: Some("white".to_owned()),
+            stroke: Some("black".to_owned()),
+            stroke_width: 1.0,
+            label: None,
+        }
+    }
+}
+
+/// A stroked polyline or polygon overlay
+#[derive(Debug, Clone)]
+struct Overlay {
+    /// Point loops (polygons) or lines
+    lines: Vec<Vec<Vec2>>,
+    /// Whether the lines are closed
+    closed: bool,
+    /// Stroke color
+    stroke: String,
+    /// Stroke width
+    width: f32,
+}
+
+/// SVG document writer for hexagonal grids, using the world/pixel coordinates of a
+/// [`HexLayout`].
+///
+/// Hexagons are drawn using [`HexLayout::hex_corners`] with a per hexagon [`HexStyle`], and
+/// can be overlaid with paths (like `algorithms::a_star` results) and [`HexRegion`] borders.
+/// Elements are drawn in insertion order and the document *view box* fits all of them.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::svg::{HexStyle, SvgWriter};
+///
+/// let layout = HexLayout {
+///     hex_size: Vec2::splat(10.0),
+///     ..Default::default()
+/// };
+/// let region: HexRegion = Hex::ZERO.range(1).collect();
+/// let svg = SvgWriter::new(&layout)
+///     .with_hexes(Hex::ZERO.range(3), |hex| HexStyle {
+///         fill: Some(if region.contains(hex) { "green" } else { "white" }.to_owned()),
+///         label: Some(hex.to_string()),
+///         ..Default::default()
+///     })
+///     .with_path(Hex::new(-3, 0).line_to(Hex::new(3, 0)), "red", 2.0)
+///     .with_region_border(&region, "black", 3.0)
+///     .render();
+/// assert!(svg.starts_with("<svg"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SvgWriter<'l> {
+    /// The layout used to compute world/pixel positions
+    layout: &'l HexLayout,
+    /// Hexagons and their style
+    hexes: Vec<(Hex, HexStyle)>,
+    /// Overlays drawn over the hexagons
+    overlays: Vec<Overlay>,
+    /// Empty space around the elements
+    margin: f32,
+    /// Optional background color
+    background: Option<String>,
+}
+
+/// Escapes XML special characters from `text`
+fn escape(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut s, c| {
+            match c {
+                '<' => s.push_str("&lt;"),
+                '>' => s.push_str("&gt;"),
+                '&' => s.push_str("&amp;"),
+                '"' => s.push_str("&quot;"),
+                '\'' => s.push_str("&apos;"),
+                c => s.push(c),
+            }
+            s
+        })
+}
+
+/// Formats `points` as a SVG `points` attribute value
+fn points_attr(points: &[Vec2]) -> String {
+    points
+        .iter()
+        .map(|p| format!("{:.3},{:.3}", p.x, p.y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl<'l> SvgWriter<'l> {
+    #[must_use]
+    /// Instantiates a new empty writer using `layout`
+    pub const fn new(layout: &'l HexLayout) -> Self {
+        Self {
+            layout,
+            hexes: Vec::new(),
+            overlays: Vec::new(),
+            margin: 0.0,
+            background: None,
+        }
+    }
+
+    #[must_use]
+    /// Adds `hexes` to the document, styled by the `style` callback
+    pub fn with_hexes(
+        mut self,
+        hexes: impl IntoIterator<Item = Hex>,
+        style: impl Fn(Hex) -> HexStyle,
+    ) -> Self {
+        self.hexes
+            .extend(hexes.into_iter().map(|hex| (hex, style(hex))));
+        self
+    }
+
+    #[must_use]
+    /// Adds a line going through the center of every coordinate of `path`, like
+    /// `algorithms::a_star` results
+    pub fn with_path(
+        mut self,
+        path: impl IntoIterator<Item = Hex>,
+        stroke: &str,
+        width: f32,
+    ) -> Self {
+        let line = path
+            .into_iter()
+            .map(|hex| self.layout.hex_to_world_pos(hex))
+            .collect();
+        self.overlays.push(Overlay {
+            lines: vec![line],
+            closed: false,
+            stroke: stroke.to_owned(),
+            width,
+        });
+        self
+    }
+
+    #[must_use]
+    /// Adds the borders of `region`, see [`HexLayout::region_outlines`]
+    pub fn with_region_border(mut self, region: &HexRegion, stroke: &str, width: f32) -> Self {
+        self.overlays.push(Overlay {
+            lines: self.layout.region_outlines(region),
+            closed: true,
+            stroke: stroke.to_owned(),
+            width,
+        });
+        self
+    }
+
+    #[must_use]
+    /// Sets the empty space around the drawn elements, in world/pixel units
+    pub const fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    #[must_use]
+    /// Sets a background color for the whole document
+    pub fn with_background(mut self, color: &str) -> Self {
+        self.background = Some(color.to_owned());
+        self
+    }
+
+    /// Computes the document `[min, max]` world/pixel bounds
+    fn view_box(&self) -> [Vec2; 2] {
+        let points = self
+            .hexes
+            .iter()
+            .flat_map(|(hex, _)| self.layout.hex_corners(*hex))
+            .chain(
+                self.overlays
+                    .iter()
+                    .flat_map(|o| o.lines.iter().flatten().copied()),
+            );
+        let [min, max] = points.fold(
+            [Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)],
+            |[min, max], p| [min.min(p), max.max(p)],
+        );
+        if min.cmpgt(max).any() {
+            return [Vec2::ZERO; 2];
+        }
+        [min - self.margin, max + self.margin]
+    }
+
+    #[must_use]
+    /// Renders the SVG document
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    /// Writes the document body
+    fn write_body(&self, f: &mut impl Write) -> fmt::Result {
+        let font_size = self.layout.hex_size.min_element().abs() / 2.0;
+        for (hex, style) in &self.hexes {
+            let fill = style
+                .fill
+                .as_deref()
+                .map_or_else(|| "none".to_owned(), escape);
+            let stroke = style
+                .stroke
+                .as_deref()
+                .map_or_else(|| "none".to_owned(), escape);
+            writeln!(
+                f,
+                r#"<polygon points="{}" fill="{fill}" stroke="{stroke}" stroke-width="{}"/>"#,
+                points_attr(&self.layout.hex_corners(*hex)),
+                style.stroke_width
+            )?;
+            if let Some(label) = &style.label {
+                let center = self.layout.hex_to_world_pos(*hex);
+                writeln!(
+                    f,
+                    r#"<text x="{:.3}" y="{:.3}" font-size="{font_size:.3}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                    center.x,
+                    center.y,
+                    escape(label)
+                )?;
+            }
+        }
+        for overlay in &self.overlays {
+            let element = if overlay.closed {
+                "polygon"
+            } else {
+                "polyline"
+            };
+            for line in &overlay.lines {
+                writeln!(
+                    f,
+                    r#"<{element} points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round"/>"#,
+                    points_attr(line),
+                    escape(&overlay.stroke),
+                    overlay.width
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for SvgWriter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let [min, max] = self.view_box();
+        let size = max - min;
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{:.3} {:.3} {:.3} {:.3}">"#,
+            min.x, min.y, size.x, size.y
+        )?;
+        if let Some(background) = &self.background {
+            writeln!(
+                f,
+                r#"<rect x="{:.3}" y="{:.3}" width="{:.3}" height="{:.3}" fill="{}"/>"#,
+                min.x,
+                min.y,
+                size.x,
+                size.y,
+                escape(background)
+            )?;
+        }
+        self.write_body(f)?;
+        write!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn document() {
+        let layout = HexLayout::default();
+        let region: HexRegion = Hex::ZERO.ring(2).collect();
+        let svg = SvgWriter::new(&layout)
+            .with_background("grey")
+            .with_margin(1.0)
+            .with_hexes(Hex::ZERO.range(3), |hex| HexStyle {
+                label: (hex == Hex::ZERO).then(|| "<origin>".to_owned()),
+                stroke: None,
+                ..Default::default()
+            })
+            .with_path([Hex::ZERO, Hex::X, Hex::new(2, 0)], "red", 0.5)
+            .with_region_border(&region, "blue", 0.2)
+            .render();
+        assert!(svg.starts_with("<svg xmlns"));
+        assert!(svg.ends_with("</svg>"));
+        assert_eq!(svg.matches("<polygon").count(), 37 + 2);
+        assert_eq!(svg.matches("<polyline").count(), 1);
+        assert_eq!(svg.matches("<text").count(), 1);
+        assert!(svg.contains("&lt;origin&gt;"));
+        assert!(svg.contains(r#"stroke="none""#));
+        assert!(svg.contains("<rect"));
+    }
+
+    #[test]
+    fn empty_document() {
+        let layout = HexLayout::default();
+        let svg = SvgWriter::new(&layout).render();
+        assert_eq!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0.000 0.000 0.000 0.000\">\n</svg>"
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{DiagonalDirection, Direction, Hex, HexEdge};
+
+/// Hexagonal vertex coordinates, representing a corner shared by three hexagons.
+///
+/// Useful for settlements, pillars or any element living on hexagon corners rather than on
+/// hexagons or edges.
+///
+/// A vertex is addressed from a [`Hex`] and the [`DiagonalDirection`] of the corner, see
+/// [`Self::from_diagonal`]. It can also be addressed from a [`Direction`]: the vertex is then
+/// the corner of `hex` between its neighbors in `direction` and `direction.left()`, see
+/// [`Self::new`].
+/// Every vertex has a single *canonical* representation: a vertex can be addressed from any of
+/// its three adjacent coordinates, the result will be the same.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let a = HexVertex::new(hex(0, 0), Direction::TopRight);
+/// let b = HexVertex::new(hex(1, -1), Direction::TopLeft);
+/// let c = HexVertex::new(hex(0, -1), Direction::Bottom);
+/// let d = HexVertex::from_diagonal(hex(0, 0), DiagonalDirection::TopRight);
+/// assert_eq!(a, b);
+/// assert_eq!(a, c);
+/// assert_eq!(a, d);
+/// assert_eq!(a.hexes(), [hex(0, 0), hex(1, -1), hex(0, -1)]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "ser_de",
+    serde(from = "(Hex, Direction)", into = "(Hex, Direction)")
+)]
+pub struct HexVertex {
+    /// The coordinate from which the vertex is addressed
+    hex: Hex,
+    /// The direction of the vertex from `hex`, either `TopRight` or `Top`
+    direction: Direction,
+}
+
+impl HexVertex {
+    #[inline]
+    #[must_use]
+    /// Instantiates the vertex of `hex` between its neighbors in `direction` and
+    /// `direction.left()`, which is the corner in the `direction.diagonal_left()` diagonal
+    /// direction.
+    ///
+    /// See [`Self::from_diagonal`]
+    pub const fn new(hex: Hex, direction: Direction) -> Self {
+        match direction {
+            Direction::TopRight | Direction::Top => Self { hex, direction },
+            Direction::TopLeft => Self {
+                hex: hex.neighbor(Direction::BottomLeft),
+                direction: Direction::TopRight,
+            },
+            Direction::BottomLeft => Self {
+                hex: hex.neighbor(Direction::Bottom),
+                direction: Direction::Top,
+            },
+            Direction::Bottom => Self {
+                hex: hex.neighbor(Direction::Bottom),
+                direction: Direction::TopRight,
+            },
+            Direction::BottomRight => Self {
+                hex: hex.neighbor(Direction::BottomRight),
+                direction: Direction::Top,
+            },
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Instantiates the vertex of `hex` in the `diagonal` direction, between its neighbors in
+    /// `diagonal.direction_right()` and `diagonal.direction_left()`
+    pub const fn from_diagonal(hex: Hex, diagonal: DiagonalDirection) -> Self {
+        Self::new(hex, diagonal.direction_right())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the canonical coordinate from which the vertex is addressed.
+    ///
+    /// See [`Self::direction`]
+    pub const fn hex(self) -> Hex {
+        self.hex
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the canonical direction of the vertex from [`Self::hex`], which is either
+    /// [`Direction::TopRight`] or [`Direction::Top`]
+    pub const fn direction(self) -> Direction {
+        self.direction
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the canonical diagonal direction of the vertex from [`Self::hex`], which is
+    /// either [`DiagonalDirection::TopRight`] or [`DiagonalDirection::TopLeft`]
+    pub const fn diagonal_direction(self) -> DiagonalDirection {
+        self.direction.diagonal_left()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the three coordinates sharing the vertex, sorted counter clockwise
+    pub const fn hexes(self) -> [Hex; 3] {
+        let (hex, dir) = (self.hex, self.direction);
+        [hex, hex.neighbor(dir), hex.neighbor(dir.left())]
+    }
+
+    #[must_use]
+    /// Returns the direction of the vertex from `hex`, or `None` if the vertex isn't a corner of
+    /// `hex`
+    pub fn direction_from(self, hex: Hex) -> Option<Direction> {
+        Direction::iter().find(|dir| Self::new(hex, *dir) == self)
+    }
+
+    #[must_use]
+    /// Returns the diagonal direction of the vertex from `hex`, or `None` if the vertex isn't a
+    /// corner of `hex`
+    pub fn diagonal_from(self, hex: Hex) -> Option<DiagonalDirection> {
+        self.direction_from(hex).map(Direction::diagonal_left)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the three edges ending on the vertex
+    pub const fn edges(self) -> [HexEdge; 3] {
+        let (hex, dir) = (self.hex, self.direction);
+        [
+            HexEdge::new(hex, dir),
+            HexEdge::new(hex, dir.left()),
+            HexEdge::new(hex.neighbor(dir), dir.rotate_left(2)),
+        ]
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the three vertices connected to `self` through one of its [`Self::edges`]
+    pub const fn neighbors(self) -> [Self; 3] {
+        let (hex, dir) = (self.hex, self.direction);
+        [
+            Self::new(hex, dir.right()),
+            Self::new(hex, dir.left()),
+            Self::new(hex.neighbor(dir), dir.left()),
+        ]
+    }
+
+    #[must_use]
+    /// Returns all 6 vertices of `hex`, following [`Direction`] order
+    pub fn all_vertices(hex: Hex) -> [Self; 6] {
+        Direction::ALL_DIRECTIONS.map(|dir| Self::new(hex, dir))
+    }
+}
+
+impl From<(Hex, Direction)> for HexVertex {
+    #[inline]
+    fn from((hex, direction): (Hex, Direction)) -> Self {
+        Self::new(hex, direction)
+    }
+}
+
+impl From<(Hex, DiagonalDirection)> for HexVertex {
+    #[inline]
+    fn from((hex, diagonal): (Hex, DiagonalDirection)) -> Self {
+        Self::from_diagonal(hex, diagonal)
+    }
+}
+
+impl From<HexVertex> for (Hex, Direction) {
+    #[inline]
+    fn from(vertex: HexVertex) -> Self {
+        (vertex.hex, vertex.direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HexBounds;
+    use std::collections::HashSet;
+
+    #[test]
+    fn canonical() {
+        for hex in Hex::new(-2, 5).range(5) {
+            for dir in Direction::iter() {
+                let vertex = HexVertex::new(hex, dir);
+                let hexes = [hex, hex.neighbor(dir), hex.neighbor(dir.left())];
+                for h in hexes {
+                    assert!(vertex.hexes().contains(&h));
+                    let d = vertex.direction_from(h).unwrap();
+                    assert_eq!(HexVertex::new(h, d), vertex);
+                }
+                assert_eq!(vertex.direction_from(hex.neighbor(dir.right())), None);
+            }
+        }
+    }
+
+    #[test]
+    fn diagonal() {
+        for hex in Hex::new(4, -1).range(3) {
+            for diagonal in DiagonalDirection::iter() {
+                let vertex = HexVertex::from((hex, diagonal));
+                assert_eq!(vertex.diagonal_from(hex), Some(diagonal));
+                // The vertex is the corner shared by the two neighbors around the diagonal
+                let [left, right] = [diagonal.direction_left(), diagonal.direction_right()];
+                assert!(vertex.hexes().contains(&hex.neighbor(left)));
+                assert!(vertex.hexes().contains(&hex.neighbor(right)));
+                assert_eq!(
+                    HexVertex::from_diagonal(vertex.hex(), vertex.diagonal_direction()),
+                    vertex
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn edges_and_neighbors() {
+        for dir in Direction::iter() {
+            let vertex = HexVertex::new(Hex::new(3, 1), dir);
+            let edges = vertex.edges();
+            for edge in edges {
+                assert!(edge.vertices().contains(&vertex));
+            }
+            for (neighbor, edge) in vertex.neighbors().into_iter().zip(edges) {
+                assert_ne!(neighbor, vertex);
+                assert!(edge.vertices().contains(&neighbor));
+                assert!(neighbor.neighbors().contains(&vertex));
+            }
+        }
+    }
+
+    #[test]
+    fn bounds_vertices() {
+        for radius in 0..5 {
+            let bounds = HexBounds::new(Hex::new(1, 2), radius);
+            let vertices: Vec<_> = bounds.all_vertices().collect();
+            let unique: HashSet<_> = bounds
+                .all_coords()
+                .flat_map(HexVertex::all_vertices)
+                .collect();
+            assert_eq!(vertices.len(), unique.len());
+            assert_eq!(vertices.len(), bounds.vertex_count());
+            assert!(vertices.iter().all(|v| unique.contains(v)));
+        }
+    }
+}